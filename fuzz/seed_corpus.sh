@@ -0,0 +1,10 @@
+#!/bin/sh
+# Seeds each fuzz target's corpus with the demo inputs for its day. Then fuzz a day's parser
+# with `cargo +nightly fuzz run day7`
+set -e
+cd "$(dirname "$0")"
+
+for day in $(seq 1 25); do
+    mkdir -p "corpus/day$day"
+    cp ../data_demo/"day${day}_"*.txt "corpus/day$day/"
+done
@@ -0,0 +1,322 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+/// One state of a simulation, drawn as text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// A line saying where the simulation is, e.g. which step it has reached
+    pub caption: String,
+    pub text: String,
+}
+
+/// Receives the states of a simulation as a solver steps through it, see
+/// [`Solution::animate`](crate::Solution::animate)
+pub trait Frames {
+    fn push(&mut self, caption: String, text: String);
+}
+
+impl Frames for Vec<Frame> {
+    fn push(&mut self, caption: String, text: String) {
+        Vec::push(self, Frame { caption, text });
+    }
+}
+
+/// What a key press asks the player to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Play or pause, starting again from the first frame when paused on the last
+    Pause,
+    Next,
+    Previous,
+    First,
+    Last,
+    Faster,
+    Slower,
+    ScrollUp,
+    ScrollDown,
+    Quit,
+}
+
+impl Control {
+    fn from_key(key: KeyEvent) -> Option<Self> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(Control::Quit);
+        }
+
+        return match key.code {
+            KeyCode::Char(' ') => Some(Control::Pause),
+            KeyCode::Right | KeyCode::Char('l') => Some(Control::Next),
+            KeyCode::Left | KeyCode::Char('h') => Some(Control::Previous),
+            KeyCode::Home | KeyCode::Char('g') => Some(Control::First),
+            KeyCode::End | KeyCode::Char('G') => Some(Control::Last),
+            KeyCode::Char('+') | KeyCode::Char('=') => Some(Control::Faster),
+            KeyCode::Char('-') => Some(Control::Slower),
+            KeyCode::Up | KeyCode::Char('k') => Some(Control::ScrollUp),
+            KeyCode::Down | KeyCode::Char('j') => Some(Control::ScrollDown),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Control::Quit),
+            _ => None,
+        };
+    }
+}
+
+/// The keys, shown under every frame
+const HELP: &str = "space play/pause, ←/→ step, home/end first/last, +/- speed, ↑/↓ scroll, q quit";
+
+/// Slowest and fastest playback, in frames per second
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 256.0;
+
+/// Plays frames back in the terminal
+pub struct Player {
+    frames: Vec<Frame>,
+    index: usize,
+    playing: bool,
+    fps: f64,
+    /// Lines scrolled past, for frames taller than the terminal
+    scroll: usize,
+}
+
+impl Player {
+    pub fn new(frames: Vec<Frame>, fps: f64) -> Self {
+        return Player {
+            frames,
+            index: 0,
+            playing: true,
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            scroll: 0,
+        };
+    }
+
+    /// Carries out a control, returning false once the player should close
+    pub fn control(&mut self, control: Control) -> bool {
+        let last = self.frames.len().saturating_sub(1);
+
+        match control {
+            Control::Pause => {
+                if !self.playing && self.index == last {
+                    self.index = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Control::Next => {
+                self.playing = false;
+                self.index = (self.index + 1).min(last);
+            }
+            Control::Previous => {
+                self.playing = false;
+                self.index = self.index.saturating_sub(1);
+            }
+            Control::First => self.index = 0,
+            Control::Last => self.index = last,
+            Control::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+            Control::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+            Control::ScrollUp => self.scroll = self.scroll.saturating_sub(1),
+            Control::ScrollDown => self.scroll += 1,
+            Control::Quit => return false,
+        }
+
+        return true;
+    }
+
+    /// Moves on a frame while playing, pausing on the last one
+    fn tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+
+        if self.index + 1 < self.frames.len() {
+            self.index += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+
+    /// Plays the frames until the viewer quits. When stdout is not a terminal the frames are
+    /// printed one after another instead
+    pub fn run(mut self) -> io::Result<()> {
+        let mut out = io::stdout();
+
+        if !out.is_terminal() {
+            return self.print(&mut out);
+        }
+
+        terminal::enable_raw_mode()?;
+        // Switch to the alternate screen and hide the cursor, so the shell is left as it was
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+
+        let result = self.play(&mut out);
+
+        write!(out, "\x1b[?25h\x1b[?1049l")?;
+        out.flush()?;
+        terminal::disable_raw_mode()?;
+
+        return result;
+    }
+
+    fn play(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let mut next_tick = Instant::now();
+
+        loop {
+            let (columns, rows) = terminal::size()?;
+            self.draw(out, columns as usize, rows as usize)?;
+
+            let timeout = match self.playing {
+                true => next_tick.saturating_duration_since(Instant::now()),
+                false => Duration::from_secs(60),
+            };
+
+            if event::poll(timeout)? {
+                // Anything else, such as a resize, only needs a redraw
+                if let Event::Key(key) = event::read()? {
+                    if let Some(control) = Control::from_key(key) {
+                        if !self.control(control) {
+                            return Ok(());
+                        }
+                    }
+                }
+                continue;
+            }
+
+            self.tick();
+            next_tick = Instant::now() + Duration::from_secs_f64(1.0 / self.fps);
+        }
+    }
+
+    /// Draws the current frame over the last one, cut to fit the terminal
+    fn draw(&mut self, out: &mut impl Write, columns: usize, rows: usize) -> io::Result<()> {
+        let Some(frame) = self.frames.get(self.index) else {
+            return Ok(());
+        };
+
+        // The caption, the status line and the help take a line each
+        let room = rows.saturating_sub(3).max(1);
+        let lines: Vec<&str> = frame.text.lines().collect();
+        self.scroll = self.scroll.min(lines.len().saturating_sub(room));
+
+        let mut screen = String::new();
+        screen.push_str("\x1b[H");
+        screen.push_str(&format!(
+            "\x1b[1m{}\x1b[0m\x1b[K\r\n",
+            fit(&frame.caption, columns)
+        ));
+
+        for line in lines.iter().skip(self.scroll).take(room) {
+            screen.push_str(&format!("{}\x1b[K\r\n", fit(line, columns)));
+        }
+
+        let status = format!(
+            "frame {}/{}, {} fps, {}",
+            self.index + 1,
+            self.frames.len(),
+            self.fps,
+            if self.playing { "playing" } else { "paused" }
+        );
+        screen.push_str(&format!(
+            "\x1b[J\x1b[{};1H{}\x1b[K\r\n",
+            rows.saturating_sub(1),
+            fit(&status, columns)
+        ));
+        screen.push_str(&format!("\x1b[2m{}\x1b[0m\x1b[K", fit(HELP, columns)));
+
+        out.write_all(screen.as_bytes())?;
+        return out.flush();
+    }
+
+    fn print(&self, out: &mut impl Write) -> io::Result<()> {
+        for frame in &self.frames {
+            writeln!(out, "{}\n{}\n", frame.caption, frame.text)?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// The start of `line` that fits in `columns` characters
+fn fit(line: &str, columns: usize) -> &str {
+    return match line.char_indices().nth(columns) {
+        Some((end, _)) => &line[..end],
+        None => line,
+    };
+}
+
+#[test]
+fn test_controls() {
+    let frames: Vec<Frame> = (0..3)
+        .map(|i| Frame {
+            caption: format!("step {}", i),
+            text: String::new(),
+        })
+        .collect();
+    let mut player = Player::new(frames, 4.0);
+
+    player.tick();
+    player.tick();
+    assert_eq!(player.index, 2);
+    assert!(player.playing);
+
+    // Playback stops on the last frame, and starts over from there
+    player.tick();
+    assert_eq!(player.index, 2);
+    assert!(!player.playing);
+    player.control(Control::Pause);
+    assert_eq!((player.index, player.playing), (0, true));
+
+    player.control(Control::Next);
+    assert_eq!((player.index, player.playing), (1, false));
+    player.control(Control::Previous);
+    player.control(Control::Previous);
+    assert_eq!(player.index, 0);
+    player.control(Control::Last);
+    assert_eq!(player.index, 2);
+
+    player.control(Control::Faster);
+    assert_eq!(player.fps, 8.0);
+    for _ in 0..20 {
+        player.control(Control::Slower);
+    }
+    assert_eq!(player.fps, MIN_FPS);
+
+    assert!(!player.control(Control::Quit));
+}
+
+#[test]
+fn test_draw() {
+    let frames = vec![Frame {
+        caption: "cycle 1".to_string(),
+        text: "#..\n.O.\n..#\n...".to_string(),
+    }];
+    let mut player = Player::new(frames, 10.0);
+
+    // Room for two lines of the frame, cut to two columns
+    let mut screen = vec![];
+    player.control(Control::ScrollDown);
+    player.draw(&mut screen, 2, 5).unwrap();
+    let screen = String::from_utf8(screen).unwrap();
+    assert!(screen.contains("\r\n.O\x1b[K\r\n..\x1b[K\r\n"));
+    assert!(!screen.contains("#."));
+
+    // Scrolling stops at the last line
+    for _ in 0..5 {
+        player.control(Control::ScrollDown);
+    }
+    player.draw(&mut vec![], 2, 5).unwrap();
+    assert_eq!(player.scroll, 2);
+}
+
+#[test]
+fn test_fit() {
+    assert_eq!(fit("abc", 2), "ab");
+    assert_eq!(fit("ab", 2), "ab");
+    assert_eq!(fit("←→", 1), "←");
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::{read_input, Answer, Error, Result};
+
+/// Known answers for the real inputs, read from a TOML file keyed by day and part:
+///
+/// ```toml
+/// [day1]
+/// part1 = 54239
+/// part2 = 55343
+/// ```
+pub struct Answers {
+    answers: HashMap<(usize, u8), String>,
+}
+
+/// The outcome of checking one part against the answers file
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Self> {
+        return Answers::parse(&read_input(path)?);
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let table = match contents.parse::<toml::Table>() {
+            Ok(x) => x,
+            Err(e) => {
+                let offset = e.span().map_or(0, |span| span.start);
+                return Err(error_at(contents, offset, e.message()));
+            }
+        };
+
+        let mut answers = HashMap::new();
+
+        for (day_key, parts) in table.iter() {
+            let day = match day_key.strip_prefix("day").and_then(|d| d.parse().ok()) {
+                Some(day) if (1..=25).contains(&day) => day,
+                _ => {
+                    return Err(error_at(
+                        contents,
+                        key_offset(contents, day_key),
+                        format!(
+                            "expected a table `[day1]` to `[day25]`, found `{}`",
+                            day_key
+                        ),
+                    ))
+                }
+            };
+
+            let parts = match parts.as_table() {
+                Some(x) => x,
+                None => {
+                    return Err(error_at(
+                        contents,
+                        key_offset(contents, day_key),
+                        format!("`{}` must be a table of parts", day_key),
+                    ))
+                }
+            };
+
+            for (part_key, value) in parts.iter() {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => {
+                        return Err(error_at(
+                            contents,
+                            key_offset(contents, part_key),
+                            format!("expected `part1` or `part2`, found `{}`", part_key),
+                        ))
+                    }
+                };
+
+                let expected = match value {
+                    toml::Value::Integer(x) => x.to_string(),
+                    toml::Value::Float(x) => x.to_string(),
+                    toml::Value::String(x) => x.clone(),
+                    _ => {
+                        return Err(error_at(
+                            contents,
+                            key_offset(contents, part_key),
+                            format!(
+                                "the answer to {}.{} must be a number or string",
+                                day_key, part_key
+                            ),
+                        ))
+                    }
+                };
+
+                answers.insert((day, part), expected);
+            }
+        }
+
+        return Ok(Self { answers });
+    }
+
+    pub fn expected(&self, day: usize, part: u8) -> Option<&str> {
+        return self.answers.get(&(day, part)).map(|a| a.as_str());
+    }
+
+    /// Compares an answer by its printed form, so `1.5` only matches a float answer of 1.5
+    pub fn check(&self, day: usize, part: u8, answer: &Answer) -> Verdict {
+        return match self.expected(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        };
+    }
+}
+
+/// Finds where `key` is first written in the file, so errors can point at it
+fn key_offset(contents: &str, key: &str) -> usize {
+    return contents.find(key).unwrap_or(0);
+}
+
+pub(crate) fn error_at(contents: &str, offset: usize, message: impl Into<String>) -> Error {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count();
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1);
+
+    return Error::parse(line, column, message);
+}
+
+#[test]
+fn test_check() {
+    let answers = Answers::parse("[day1]\npart1 = 142\n\n[day24]\npart2 = 47\n").unwrap();
+
+    assert_eq!(answers.check(1, 1, &Answer::Integer(142)), Verdict::Pass);
+    assert_eq!(
+        answers.check(1, 1, &Answer::Integer(141)),
+        Verdict::Fail {
+            expected: "142".to_string()
+        }
+    );
+    assert_eq!(answers.check(1, 2, &Answer::Integer(281)), Verdict::Missing);
+    assert_eq!(
+        answers.check(24, 2, &Answer::Float(47.3)),
+        Verdict::Fail {
+            expected: "47".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_bad_answers_file() {
+    let error = Answers::parse("[day1]\npart3 = 142\n").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected `part1` or `part2`, found `part3`"
+    );
+}
@@ -0,0 +1,220 @@
+use std::{fmt::Display, fs, time::Duration, time::Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{input::Input, read_input, Answer, Error, Registration, Result};
+
+/// The timed phases of solving a day
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part1 => f.pad("part 1"),
+            Stage::Part2 => f.pad("part 2"),
+        }
+    }
+}
+
+/// Summary of repeated timings of one stage
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        if n == 0 {
+            return Stats {
+                runs: 0,
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        return Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        };
+    }
+}
+
+/// Times parsing and the chosen parts of one day, `warmup` times untimed and then `runs` times.
+/// A part that errors keeps its error and is not run again, while the other parts carry on
+pub fn run(
+    registration: &Registration,
+    path: &str,
+    parts: &[Stage],
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<(Stage, Result<Stats>)>> {
+    let input = Input::open(path)?;
+    let input = input.text();
+
+    let mut samples: Vec<(Stage, Result<Vec<Duration>>)> = vec![(Stage::Parse, Ok(vec![]))];
+    samples.extend(parts.iter().map(|stage| (*stage, Ok(vec![]))));
+
+    for run in 0..warmup + runs {
+        let mut times = vec![];
+
+        let now = Instant::now();
+        let solution = (registration.parse)(input)?;
+        times.push(Ok(Some(now.elapsed())));
+
+        for (stage, stage_samples) in samples.iter().skip(1) {
+            if stage_samples.is_err() {
+                times.push(Ok(None));
+                continue;
+            }
+
+            let now = Instant::now();
+            let answer = match stage {
+                Stage::Part1 => solution.part1(),
+                Stage::Part2 => solution.part2(),
+                Stage::Parse => Ok(Answer::None),
+            };
+            let time = now.elapsed();
+
+            // Parts that do not exist, such as day 25 part 2, are not worth timing
+            times.push(answer.map(|answer| {
+                if answer == Answer::None {
+                    None
+                } else {
+                    Some(time)
+                }
+            }));
+        }
+
+        for ((_, stage_samples), time) in samples.iter_mut().zip(times) {
+            match (stage_samples.as_mut(), time) {
+                (Ok(stage_samples), Ok(Some(time))) if run >= warmup => stage_samples.push(time),
+                (Ok(_), Err(e)) => *stage_samples = Err(e),
+                _ => {}
+            }
+        }
+    }
+
+    return Ok(samples
+        .into_iter()
+        .filter_map(|(stage, s)| match s {
+            Ok(s) if s.is_empty() => None,
+            Ok(s) => Some((stage, Ok(Stats::new(&s)))),
+            Err(e) => Some((stage, Err(e))),
+        })
+        .collect());
+}
+
+/// Median times from a previous run, saved as JSON so later runs can spot regressions
+#[derive(Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub day: usize,
+    pub stage: Stage,
+    pub median_ns: u64,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = read_input(path)?;
+
+        return serde_json::from_str(&contents).map_err(|e| {
+            Error::parse(
+                e.line().saturating_sub(1),
+                e.column().saturating_sub(1),
+                e.to_string(),
+            )
+        });
+    }
+
+    pub fn save(&self, path: &str) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self).expect("Baselines always serialise");
+
+        return fs::write(path, contents).map_err(|source| Error::Io {
+            path: path.to_string(),
+            source,
+        });
+    }
+
+    pub fn insert(&mut self, day: usize, stage: Stage, stats: &Stats) {
+        self.entries.retain(|e| e.day != day || e.stage != stage);
+        self.entries.push(BaselineEntry {
+            day,
+            stage,
+            median_ns: stats.median.as_nanos() as u64,
+        });
+    }
+
+    pub fn median(&self, day: usize, stage: Stage) -> Option<Duration> {
+        return self
+            .entries
+            .iter()
+            .find(|e| e.day == day && e.stage == stage)
+            .map(|e| Duration::from_nanos(e.median_ns));
+    }
+}
+
+/// Relative change of `now` against `before`, as a percentage
+pub fn change(before: Duration, now: Duration) -> f64 {
+    if before.is_zero() {
+        return 0.0;
+    }
+
+    return (now.as_secs_f64() - before.as_secs_f64()) / before.as_secs_f64() * 100.0;
+}
+
+#[test]
+fn test_stats() {
+    let samples: Vec<Duration> = [4, 1, 3, 2]
+        .iter()
+        .map(|ms| Duration::from_millis(*ms))
+        .collect();
+    let stats = Stats::new(&samples);
+
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    // sqrt(1.25) ms
+    assert_eq!(stats.stddev.as_micros(), 1118);
+}
+
+#[test]
+fn test_change() {
+    let before = Duration::from_millis(10);
+    assert!((change(before, Duration::from_millis(15)) - 50.0).abs() < 1e-9);
+    assert!((change(before, Duration::from_millis(5)) + 50.0).abs() < 1e-9);
+}
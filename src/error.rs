@@ -0,0 +1,72 @@
+use std::{fmt::Display, io};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file could not be read
+    Io { path: String, source: io::Error },
+    /// The input is malformed. Lines and columns are 1-based
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed, but the puzzle has no answer for it
+    Unsolvable(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Builds a parse error from 0-based line and column indices
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        return Error::Parse {
+            line: line + 1,
+            column: column + 1,
+            message: message.into(),
+        };
+    }
+
+    /// Builds a parse error pointing at `part`, which must be a slice of `line`
+    pub fn parse_at(line_index: usize, line: &str, part: &str, message: impl Into<String>) -> Self {
+        return Error::parse(line_index, offset_in(line, part), message);
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        return Error::Unsolvable(message.into());
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "could not read input `{}`: {}", path, source),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Unsolvable(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Byte offset of `part` within `line`, or 0 if `part` is not a slice of `line`
+pub fn offset_in(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if part_start < start || part_start > start + line.len() {
+        return 0;
+    }
+
+    return part_start - start;
+}
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{
+    distributions::uniform::{SampleRange, SampleUniform},
+    rngs::StdRng,
+    seq::SliceRandom,
+    Rng, SeedableRng,
+};
+
+/// Random choices for building synthetic puzzle inputs. The same seed and size always give the
+/// same input
+pub struct Generator {
+    rng: StdRng,
+    size: Option<usize>,
+}
+
+impl Generator {
+    pub fn new(seed: u64, size: Option<usize>) -> Self {
+        return Generator {
+            rng: StdRng::seed_from_u64(seed),
+            size,
+        };
+    }
+
+    /// The size asked for, or `default` if none was. What it counts depends on the day
+    pub fn size_or(&self, default: usize) -> usize {
+        return self.size.unwrap_or(default);
+    }
+
+    pub fn range<T: SampleUniform, R: SampleRange<T>>(&mut self, range: R) -> T {
+        return self.rng.gen_range(range);
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        return self.rng.gen_bool(p.clamp(0.0, 1.0));
+    }
+
+    /// One of `items`, which must not be empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return items.choose(&mut self.rng).expect("nothing to choose from");
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        items.shuffle(&mut self.rng);
+    }
+
+    /// A word of `len` characters drawn from `alphabet`
+    pub fn word(&mut self, alphabet: &str, len: usize) -> String {
+        let letters: Vec<char> = alphabet.chars().collect();
+        return (0..len).map(|_| *self.choose(&letters)).collect();
+    }
+
+    /// `count` different words of `len` lowercase letters, none of them in `taken`
+    pub fn names(&mut self, count: usize, len: usize, taken: &[&str]) -> Vec<String> {
+        let mut seen: HashSet<String> = taken.iter().map(|t| t.to_string()).collect();
+        let mut names = vec![];
+
+        while names.len() < count {
+            let name = self.word("abcdefghijklmnopqrstuvwxyz", len);
+            if seen.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+
+        return names;
+    }
+
+    /// A closed loop that never touches itself, through points of a `height` by `width` grid,
+    /// as the points it visits in order. It is the outline of a random tree, so it winds about
+    /// the grid, with room for points inside it everywhere. Grids smaller than 3 by 3 are
+    /// treated as 3 by 3
+    pub fn loop_path(&mut self, height: usize, width: usize) -> Vec<(usize, usize)> {
+        let rows = ((height + 1) / 4).max(1);
+        let columns = ((width + 1) / 4).max(1);
+
+        // A random spanning tree of a `rows` by `columns` grid of nodes, drawn on a grid of cells
+        // twice the size so that the edges take up the cells between the nodes. The outline of
+        // a tree has no holes, and every pair of cells touching at a corner shares a node, so
+        // the outline passes each point at most once
+        let mut tree = vec![vec![false; 2 * columns - 1]; 2 * rows - 1];
+
+        let mut visited = vec![vec![false; columns]; rows];
+        let mut stack = vec![(self.range(0..rows), self.range(0..columns))];
+        visited[stack[0].0][stack[0].1] = true;
+        tree[2 * stack[0].0][2 * stack[0].1] = true;
+
+        while let Some(&(r, c)) = stack.last() {
+            let mut next = vec![];
+            if r > 0 && !visited[r - 1][c] {
+                next.push((r - 1, c));
+            }
+            if r + 1 < rows && !visited[r + 1][c] {
+                next.push((r + 1, c));
+            }
+            if c > 0 && !visited[r][c - 1] {
+                next.push((r, c - 1));
+            }
+            if c + 1 < columns && !visited[r][c + 1] {
+                next.push((r, c + 1));
+            }
+
+            if next.is_empty() {
+                stack.pop();
+                continue;
+            }
+
+            let (nr, nc) = *self.choose(&next);
+            visited[nr][nc] = true;
+            tree[2 * nr][2 * nc] = true;
+            tree[r + nr][c + nc] = true;
+            stack.push((nr, nc));
+        }
+
+        // Each cell of the tree becomes two by two, so the loop is never squeezed against itself
+        let cells: Vec<Vec<bool>> = tree
+            .iter()
+            .flat_map(|row| {
+                let row: Vec<bool> = row.iter().flat_map(|&cell| [cell, cell]).collect();
+                return [row.clone(), row];
+            })
+            .collect();
+        let cell_rows = cells.len();
+        let cell_columns = cells[0].len();
+
+        // Walk the outline clockwise, from corner point to corner point
+        let filled = |r: i64, c: i64| {
+            r >= 0
+                && c >= 0
+                && (r as usize) < cell_rows
+                && (c as usize) < cell_columns
+                && cells[r as usize][c as usize]
+        };
+
+        let mut next = HashMap::new();
+        for (r, row) in cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                if !cell {
+                    continue;
+                }
+
+                let (ri, ci) = (r as i64, c as i64);
+                if !filled(ri - 1, ci) {
+                    next.insert((r, c), (r, c + 1));
+                }
+                if !filled(ri, ci + 1) {
+                    next.insert((r, c + 1), (r + 1, c + 1));
+                }
+                if !filled(ri + 1, ci) {
+                    next.insert((r + 1, c + 1), (r + 1, c));
+                }
+                if !filled(ri, ci - 1) {
+                    next.insert((r + 1, c), (r, c));
+                }
+            }
+        }
+
+        let start = *next.keys().min().expect("the tree has at least one node");
+        let mut path = vec![start];
+        let mut point = next[&start];
+
+        while point != start {
+            path.push(point);
+            point = next[&point];
+        }
+
+        return path;
+    }
+}
+
+#[test]
+fn test_seed() {
+    let mut a = Generator::new(7, None);
+    let mut b = Generator::new(7, None);
+
+    let a: Vec<usize> = (0..10).map(|_| a.range(0..1000)).collect();
+    let b: Vec<usize> = (0..10).map(|_| b.range(0..1000)).collect();
+    assert_eq!(a, b);
+
+    assert_eq!(Generator::new(0, Some(3)).size_or(10), 3);
+    assert_eq!(Generator::new(0, None).size_or(10), 10);
+}
+
+#[test]
+fn test_names() {
+    let mut generator = Generator::new(1, None);
+    let names = generator.names(20, 2, &["in"]);
+
+    let unique: HashSet<&String> = names.iter().collect();
+    assert_eq!(unique.len(), 20);
+    assert!(names.iter().all(|n| n.len() == 2 && n != "in"));
+}
+
+#[test]
+fn test_loop_path() {
+    for seed in 0..20 {
+        let mut generator = Generator::new(seed, None);
+        let path = generator.loop_path(12, 9);
+        assert!(path.len() > 8);
+
+        // Every step moves to a neighbouring point, and the loop closes
+        for (a, b) in path.iter().zip(path.iter().cycle().skip(1)) {
+            assert_eq!(
+                a.0.abs_diff(b.0) + a.1.abs_diff(b.1),
+                1,
+                "{:?} to {:?}",
+                a,
+                b
+            );
+        }
+
+        let unique: HashSet<&(usize, usize)> = path.iter().collect();
+        assert_eq!(unique.len(), path.len());
+        assert!(path.iter().all(|&(r, c)| r < 12 && c < 9));
+    }
+
+    assert_eq!(Generator::new(0, None).loop_path(3, 3).len(), 8);
+}
@@ -0,0 +1,231 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A compass direction on a grid drawn with north at the top, so moving north decreases `y`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        return match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        };
+    }
+
+    pub fn turn_right(self) -> Self {
+        return match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        };
+    }
+
+    pub fn reverse(self) -> Self {
+        return match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        };
+    }
+
+    /// The step taken by moving one cell this way
+    pub fn delta(self) -> Point {
+        return match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        };
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::North => f.pad("^"),
+            Direction::East => f.pad(">"),
+            Direction::South => f.pad("v"),
+            Direction::West => f.pad("<"),
+        }
+    }
+}
+
+/// A 2D integer point, with `x` the column and `y` the row when used on a grid
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        return Point { x, y };
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs();
+    }
+
+    /// The point one step away in `direction`
+    pub fn step(self, direction: Direction) -> Point {
+        return self + direction.delta();
+    }
+
+    /// The four points one step away, clockwise from north
+    pub fn neighbours(self) -> [Point; 4] {
+        return Direction::ALL.map(|d| self.step(d));
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        return Point::new(self.x + rhs.x, self.y + rhs.y);
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        return Point::new(self.x - rhs.x, self.y - rhs.y);
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        return Point::new(self.x * rhs, self.y * rhs);
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        return Point::new(-self.x, -self.y);
+    }
+}
+
+/// A 3D integer point
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        return Point3 { x, y, z };
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        return (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs();
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        return Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z);
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Point3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        return Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z);
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Point3) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: i64) -> Point3 {
+        return Point3::new(self.x * rhs, self.y * rhs, self.z * rhs);
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        return Point3::new(-self.x, -self.y, -self.z);
+    }
+}
+
+#[test]
+fn test_direction() {
+    for d in Direction::ALL {
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.reverse(), d.turn_right().turn_right());
+        assert_eq!(d.delta() + d.reverse().delta(), Point::default());
+    }
+
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Point::new(2, 3).step(Direction::North), Point::new(2, 2));
+}
+
+#[test]
+fn test_points() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 4);
+
+    assert_eq!(a + b, Point::new(-2, 2));
+    assert_eq!(a - b, Point::new(4, -6));
+    assert_eq!(a * 3, Point::new(3, -6));
+    assert_eq!(a.manhattan(b), 10);
+
+    let c = Point3::new(1, 2, 3);
+    assert_eq!(c - Point3::new(0, 0, 5), Point3::new(1, 2, -2));
+    assert_eq!(c.manhattan(-c), 12);
+}
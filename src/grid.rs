@@ -0,0 +1,335 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{geometry::Point, parse, Error, Result};
+
+/// A rectangular grid of cells, indexed by `(row, column)` from the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Offsets to the four orthogonal neighbours: north, east, south and west
+pub const NEIGHBOURS_4: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets to all eight neighbours, clockwise from north
+pub const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        return Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        };
+    }
+
+    /// Builds a grid from rows, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::parse(
+                    i,
+                    row.len(),
+                    format!("expected {} columns, found {}", width, row.len()),
+                ));
+            }
+
+            cells.extend(row);
+        }
+
+        return Ok(Grid {
+            width,
+            height,
+            cells,
+        });
+    }
+
+    /// Parses one cell per character, reporting the position of any character `cell` rejects
+    pub fn parse_with<S: AsRef<str>>(
+        lines: &[S],
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self> {
+        return parse::grid(0, lines, cell);
+    }
+
+    /// Parses one cell per character with the cell type's `TryFrom<char>`
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self>
+    where
+        T: TryFrom<char>,
+    {
+        return Grid::parse_with(lines, |c| T::try_from(c).ok());
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i >= self.height || j >= self.width {
+            return None;
+        }
+
+        return Some(&self.cells[i * self.width + j]);
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.height || j >= self.width {
+            return None;
+        }
+
+        return Some(&mut self.cells[i * self.width + j]);
+    }
+
+    /// Like `get`, but takes signed coordinates so that stepping off the top or left is `None`
+    pub fn get_signed(&self, i: i64, j: i64) -> Option<&T> {
+        if i < 0 || j < 0 {
+            return None;
+        }
+
+        return self.get(i as usize, j as usize);
+    }
+
+    /// Like `get`, with the point's `x` as the column and `y` as the row
+    pub fn get_point(&self, p: Point) -> Option<&T> {
+        return self.get_signed(p.y, p.x);
+    }
+
+    /// Treats the grid as tiling the plane forever in every direction
+    pub fn get_wrapping(&self, i: i64, j: i64) -> &T {
+        let i = i.rem_euclid(self.height as i64) as usize;
+        let j = j.rem_euclid(self.width as i64) as usize;
+
+        return &self[(i, j)];
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        return &self.cells[i * self.width..(i + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> {
+        return (0..self.height).map(move |i| &self[(i, j)]);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(move |j| self.column(j));
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(k, cell)| ((k / self.width, k % self.width), cell));
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        return self
+            .cells
+            .iter()
+            .position(predicate)
+            .map(|k| (k / self.width, k % self.width));
+    }
+
+    /// The orthogonal neighbours of `(i, j)` that are on the grid
+    pub fn neighbours_4(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.offsets(i, j, &NEIGHBOURS_4);
+    }
+
+    /// The orthogonal and diagonal neighbours of `(i, j)` that are on the grid
+    pub fn neighbours_8(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.offsets(i, j, &NEIGHBOURS_8);
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        i: usize,
+        j: usize,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        return offsets.iter().filter_map(move |(di, dj)| {
+            let ni = i as i64 + di;
+            let nj = j as i64 + dj;
+
+            if ni < 0 || nj < 0 || ni as usize >= self.height || nj as usize >= self.width {
+                return None;
+            }
+
+            return Some((ni as usize, nj as usize));
+        });
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        };
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+
+        for j in 0..self.width {
+            cells.extend(self.column(j).cloned());
+        }
+
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        };
+    }
+
+    /// Rotates a quarter turn clockwise, so the first column becomes the first row reversed
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+
+        for j in 0..self.width {
+            cells.extend(self.column(j).rev().cloned());
+        }
+
+        return Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        };
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(
+            i < self.height && j < self.width,
+            "({}, {}) is off the grid",
+            i,
+            j
+        );
+        return &self.cells[i * self.width + j];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            i < self.height && j < self.width,
+            "({}, {}) is off the grid",
+            i,
+            j
+        );
+        return &mut self.cells[i * self.width + j];
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(p.x >= 0 && p.y >= 0, "{:?} is off the grid", p);
+        return &self[(p.y as usize, p.x as usize)];
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(p.x >= 0 && p.y >= 0, "{:?} is off the grid", p);
+        return &mut self[(p.y as usize, p.x as usize)];
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[test]
+fn test_parse() {
+    let grid: Grid<char> = Grid::parse(&["ab", "cd", "ef"]).unwrap();
+
+    assert_eq!(grid.width(), 2);
+    assert_eq!(grid.height(), 3);
+    assert_eq!(grid[(2, 1)], 'f');
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get_signed(-1, 0), None);
+    assert_eq!(grid[Point::new(0, 2)], 'e');
+    assert_eq!(*grid.get_wrapping(-1, 2), 'e');
+    assert_eq!(grid.to_string(), "ab\ncd\nef");
+
+    let error = Grid::<char>::parse(&["ab", "c"]).err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 2: expected 2 columns, found 1"
+    );
+}
+
+#[test]
+fn test_transform() {
+    let grid: Grid<char> = Grid::parse(&["ab", "cd", "ef"]).unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb");
+    assert_eq!(grid.column(1).collect::<String>(), "bdf");
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0);
+
+    assert_eq!(
+        grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0)]
+    );
+    assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours_8(2, 2).count(), 3);
+}
@@ -0,0 +1,196 @@
+use std::{
+    fs::{self, File},
+    io,
+};
+
+use memmap2::Mmap;
+
+use crate::{read_input, Error, Result};
+
+/// Inputs at least this large are memory-mapped rather than read into memory
+pub const MAP_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// A puzzle input, held in memory or memory-mapped when it is large
+pub struct Input {
+    contents: Contents,
+}
+
+enum Contents {
+    Text(String),
+    /// Checked to be UTF-8 when mapped
+    Mapped(Mmap),
+}
+
+impl Input {
+    /// Reads the file at `path`, or stdin for `-`, mapping it instead if it is very large
+    pub fn open(path: &str) -> Result<Self> {
+        if path != "-" {
+            let metadata = fs::metadata(path).map_err(|source| io_error(path, source))?;
+            if metadata.len() >= MAP_THRESHOLD {
+                return Input::map(path);
+            }
+        }
+
+        return Input::read(path);
+    }
+
+    /// Reads the whole file at `path`, or stdin for `-`, into memory
+    pub fn read(path: &str) -> Result<Self> {
+        return Ok(Input {
+            contents: Contents::Text(read_input(path)?),
+        });
+    }
+
+    /// Memory-maps the file at `path`. The file must not be changed while the input is alive
+    pub fn map(path: &str) -> Result<Self> {
+        let file = File::open(path).map_err(|source| io_error(path, source))?;
+
+        // SAFETY: the file is only read, and the caller keeps it unchanged while it is mapped
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|source| io_error(path, source))?;
+
+        if let Err(e) = std::str::from_utf8(&mmap) {
+            return Err(io_error(
+                path,
+                io::Error::new(io::ErrorKind::InvalidData, e),
+            ));
+        }
+
+        return Ok(Input {
+            contents: Contents::Mapped(mmap),
+        });
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        return matches!(self.contents, Contents::Mapped(_));
+    }
+
+    /// The whole input as it was read, line endings included
+    pub fn text(&self) -> &str {
+        return match &self.contents {
+            Contents::Text(text) => text,
+            // SAFETY: checked to be UTF-8 in `Input::map`
+            Contents::Mapped(mmap) => unsafe { std::str::from_utf8_unchecked(mmap) },
+        };
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        return lines(self.text());
+    }
+
+    pub fn blocks(&self) -> impl Iterator<Item = Block<'_>> {
+        return blocks(self.text());
+    }
+}
+
+fn io_error(path: &str, source: io::Error) -> Error {
+    return Error::Io {
+        path: path.to_string(),
+        source,
+    };
+}
+
+/// The lines of `text` without their `\n` or `\r\n`. A final line ending does not start
+/// another, empty, line
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+
+    return text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .take(if text.is_empty() { 0 } else { usize::MAX });
+}
+
+/// A run of non-blank lines, with the 0-based index of its first line in the input
+#[derive(Debug, PartialEq)]
+pub struct Block<'a> {
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    /// The lines with their 0-based indices in the input, for error messages
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        return self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| (self.first_line + i, *line));
+    }
+}
+
+/// The runs of lines in `text` separated by blank lines. Extra blank lines do not make empty blocks
+pub fn blocks(text: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut lines = lines(text).enumerate().peekable();
+
+    return std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+
+        let (first_line, line) = lines.next()?;
+        let mut block = Block {
+            first_line,
+            lines: vec![line],
+        };
+
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            block.lines.push(line);
+        }
+
+        return Some(block);
+    });
+}
+
+#[test]
+fn test_lines() {
+    let collect = |text| lines(text).collect::<Vec<_>>();
+
+    assert_eq!(collect("a\nb"), vec!["a", "b"]);
+    assert_eq!(collect("a\r\nb\r\n"), vec!["a", "b"]);
+    assert_eq!(collect("a\n\nb\n"), vec!["a", "", "b"]);
+    assert_eq!(collect("a\n\n"), vec!["a", ""]);
+    assert_eq!(collect("\n"), Vec::<&str>::new());
+    assert_eq!(collect(""), Vec::<&str>::new());
+}
+
+#[test]
+fn test_blocks() {
+    let found: Vec<Block> = blocks("seeds\r\n\r\na\nb\n\n\n c\n").collect();
+
+    assert_eq!(
+        found,
+        vec![
+            Block {
+                first_line: 0,
+                lines: vec!["seeds"]
+            },
+            Block {
+                first_line: 2,
+                lines: vec!["a", "b"]
+            },
+            Block {
+                first_line: 6,
+                lines: vec![" c"]
+            },
+        ]
+    );
+    assert_eq!(
+        found[1].numbered().collect::<Vec<_>>(),
+        vec![(2, "a"), (3, "b")]
+    );
+}
+
+#[test]
+fn test_map() {
+    let path = std::env::temp_dir().join(format!("aoc_input_{}.txt", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+    fs::write(&path, "1\r\n2\r\n").unwrap();
+
+    let input = Input::map(&path).unwrap();
+    assert!(input.is_mapped());
+    assert_eq!(input.lines().collect::<Vec<_>>(), vec!["1", "2"]);
+
+    fs::write(&path, [0xff, 0xfe]).unwrap();
+    assert!(Input::map(&path).is_err());
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,246 @@
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+pub use error::{Error, Result};
+pub use solution::{parse_boxed, Answer, Demo, Registration, Solution};
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod profile;
+pub mod render;
+pub mod solution;
+pub mod store;
+pub mod watch;
+
+/// Solutions for the 2023 event
+pub mod y2023 {
+    pub mod day1;
+    pub mod day2;
+    pub mod day3;
+    pub mod day4;
+    pub mod day5;
+    pub mod day6;
+    pub mod day7;
+    pub mod day8;
+    pub mod day9;
+    pub mod day10;
+    pub mod day11;
+    pub mod day12;
+    pub mod day13;
+    pub mod day14;
+    pub mod day15;
+    pub mod day16;
+    pub mod day17;
+    pub mod day18;
+    pub mod day19;
+    pub mod day20;
+    pub mod day21;
+    pub mod day22;
+    pub mod day23;
+    pub mod day24;
+    pub mod day25;
+
+    /// Days left out of this build, with the cargo feature each needs
+    pub const COMPILED_OUT: &[(usize, &str)] = &[];
+}
+
+/// Every year's days left out of this build
+const COMPILED_OUT: &[(u16, &[(usize, &str)])] = &[(2023, y2023::COMPILED_OUT)];
+
+/// Every registered solution, ordered by year and day
+pub fn solutions() -> Vec<&'static Registration> {
+    let mut solutions: Vec<&Registration> = inventory::iter::<Registration>.into_iter().collect();
+    solutions.sort_by_key(|r| (r.year, r.day));
+    return solutions;
+}
+
+/// The years with at least one solution, oldest first
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = solutions().iter().map(|r| r.year).collect();
+    years.dedup();
+    return years;
+}
+
+/// Looks up the registered solution for a day of a year
+pub fn find_solution(year: u16, day: usize) -> Option<&'static Registration> {
+    return inventory::iter::<Registration>
+        .into_iter()
+        .find(|r| r.year == year && r.day == day);
+}
+
+/// Days left out of this build because a cargo feature they need is off, with that feature
+pub fn compiled_out() -> Vec<(u16, usize, &'static str)> {
+    return COMPILED_OUT
+        .iter()
+        .flat_map(|(year, days)| days.iter().map(|(day, feature)| (*year, *day, *feature)))
+        .collect();
+}
+
+/// Reads a whole input file, or stdin when `path` is `-`
+pub fn read_input(path: &str) -> Result<String> {
+    let io_error = |source| Error::Io {
+        path: path.to_string(),
+        source,
+    };
+
+    if path != "-" {
+        return fs::read_to_string(path).map_err(io_error);
+    }
+
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(io_error)?;
+
+    // Piped input almost always ends with a newline, which the data files do not
+    if contents.ends_with('\n') {
+        contents.pop();
+    }
+
+    return Ok(contents);
+}
+
+/// Splits puzzle input into lines, without line endings or a trailing empty line
+pub fn lines(input: &str) -> Vec<String> {
+    return input::lines(input).map(|c| c.to_string()).collect();
+}
+
+/// Reads and parses a demo file for the tests
+#[cfg(test)]
+fn load<S: Solution>(path: &str) -> S {
+    let input = read_input(path).unwrap_or_else(|e| panic!("{}", e));
+
+    return S::parse(&input).unwrap_or_else(|e| panic!("Could not parse {}: {}", path, e));
+}
+
+#[test]
+fn test_demos() {
+    for registration in solutions() {
+        for demo in registration.demos {
+            let solution = (registration.parse)(&read_input(demo.input).unwrap()).unwrap();
+
+            let answer = match demo.part {
+                1 => solution.part1_demo(),
+                _ => solution.part2_demo(),
+            };
+
+            assert_eq!(
+                answer.unwrap(),
+                demo.expected,
+                "day {} part {} ({})",
+                registration.day,
+                demo.part,
+                demo.input
+            );
+        }
+    }
+}
+
+#[test]
+fn test_render_demos() {
+    for registration in solutions() {
+        for demo in registration.demos {
+            let solution = (registration.parse)(&read_input(demo.input).unwrap()).unwrap();
+
+            if let Some(picture) = solution.render().unwrap() {
+                assert!(picture.width() > 0 && picture.height() > 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_animate_demos() {
+    for registration in solutions() {
+        for demo in registration.demos {
+            let solution = (registration.parse)(&read_input(demo.input).unwrap()).unwrap();
+
+            let mut frames: Vec<animate::Frame> = vec![];
+            if solution.animate(&mut frames).unwrap() {
+                assert!(!frames.is_empty(), "day {}", registration.day);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate() {
+    for registration in solutions() {
+        // Part 2 of day 6 joins the races into one number, so only a few fit
+        let size = match registration.day {
+            6 => 2,
+            _ => 10,
+        };
+
+        // Even a size of 0 has to give an input the day can solve
+        for size in [0, size] {
+            let generate = || (registration.generate)(&mut generate::Generator::new(1, Some(size)));
+
+            let input = generate();
+            assert_eq!(input, generate(), "day {}", registration.day);
+
+            let solution = (registration.parse)(&input)
+                .unwrap_or_else(|e| panic!("day {}: {}\n{}", registration.day, e, input));
+
+            if let Err(e) = solution.part1() {
+                panic!("day {} part 1: {}\n{}", registration.day, e, input);
+            }
+
+            // Some part 2s only work on inputs shaped like the real one
+            match solution.part2() {
+                Ok(_) | Err(Error::Unsolvable(_)) => {}
+                Err(e) => panic!("day {} part 2: {}\n{}", registration.day, e, input),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    /// Damaged demo inputs give parse errors rather than panics. The `fuzz/` targets search
+    /// much further from the demos than this
+    #[test]
+    fn test_parse_damaged_demos(
+        edits in proptest::collection::vec(
+            (
+                0..3u8,
+                proptest::num::usize::ANY,
+                proptest::prop_oneof![
+                    proptest::sample::select(
+                        "0123456789-+,.:;=<>{}()#?%&@|~SLRJF7 \nxmas".chars().collect::<Vec<_>>(),
+                    ),
+                    proptest::char::any(),
+                ],
+            ),
+            1..8,
+        ),
+    ) {
+        for registration in solutions() {
+            for demo in registration.demos {
+                let mut input: Vec<char> = read_input(demo.input).unwrap().chars().collect();
+
+                for (kind, at, c) in &edits {
+                    let at = at % (input.len() + 1);
+                    match kind {
+                        0 => input.insert(at, *c),
+                        1 if at < input.len() => input[at] = *c,
+                        _ => input.truncate(at),
+                    }
+                }
+
+                let _ = (registration.parse)(&input.iter().collect::<String>());
+            }
+        }
+    }
+}
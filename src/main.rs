@@ -1,150 +1,1370 @@
-use clap::{Parser, ValueEnum};
-use day1::day1;
-use day10::day10;
-use day11::day11;
-use day12::day12;
-use day13::day13;
-use day14::day14;
-use day15::day15;
-use day16::day16;
-use day17::day17;
-use day18::day18;
-use day19::day19;
-use day2::day2;
-use day20::day20;
-use day21::day21;
-use day22::day22;
-use day23::day23;
-use day24::day24;
-use day25::day25;
-use day3::day3;
-use day4::day4;
-use day5::day5;
-use day6::day6;
-use day7::day7;
-use day8::day8;
-use day9::day9;
-use std::{fs::File, io::Read};
-
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day2;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+use advent_of_code_2023::{
+    animate::{Frame, Player},
+    answers::{Answers, Verdict},
+    bench::{self, Baseline, Stage},
+    compiled_out, find_solution,
+    generate::Generator,
+    input::Input,
+    profile::{self, CountingAllocator},
+    read_input, render, solutions,
+    store::{self, Checksum, InputStore},
+    watch::Watcher,
+    years, Answer, Demo, Registration, Result, Solution,
+};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use log::{warn, LevelFilter, Log};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The event chosen with --year
+static YEAR: OnceLock<u16> = OnceLock::new();
+
+/// Where inputs are kept for the profile chosen with --profile-name
+static STORE: OnceLock<InputStore> = OnceLock::new();
 
 #[derive(Parser)]
 #[command(
     author = "Nathaniel Curnick nathaniel.curnick@gmail.com",
-    version = "0.0.1"
+    version = "0.0.1",
+    subcommand_negates_reqs = true
 )]
 struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[clap(long, short, global = true, default_value_t = 2023, value_parser = year_number)]
+    /// Year of the event to run
+    pub year: u16,
+
+    #[clap(long, short, required_unless_present_any = ["all", "demo"])]
+    /// Day you wish to run, as `5` or `day5`
+    pub day: Option<Day>,
+
+    #[clap(long, short, value_enum, default_value_t = Part::Both)]
+    /// Part of the puzzle you wish to run
+    pub part: Part,
+
+    #[clap(long, short, conflicts_with_all = ["day", "input"])]
+    /// Run every day in sequence and print a table of the results
+    pub all: bool,
+
+    #[clap(long, short, requires = "all", conflicts_with_all = ["day", "demo"])]
+    /// With --all, solve each day and part as its own task on this many threads. 0 uses one per core
+    pub jobs: Option<usize>,
+
+    #[clap(long, short)]
+    /// Input file to solve, or `-` to read from stdin. Defaults to the day's file in data/
+    pub input: Option<String>,
+
+    #[clap(long, conflicts_with = "input")]
+    /// Solve the examples in data_demo/ and compare them with their known answers
+    pub demo: bool,
+
+    #[clap(long, conflicts_with_all = ["all", "demo"], value_parser = image_path)]
+    /// Draw the input and what both parts found in it to this .ppm, .png or .svg file
+    pub render: Option<String>,
+
+    #[clap(long, conflicts_with_all = ["all", "demo"])]
+    /// Play back the day's simulation in the terminal once it is solved
+    pub animate: bool,
+
+    #[clap(long, requires = "animate", default_value_t = 10.0)]
+    /// Frames per second to start playback at, with --animate
+    pub fps: f64,
+
+    #[clap(long, short, value_enum, default_value_t = Format::Text)]
+    /// How to print results. Diagnostics always go to stderr
+    pub format: Format,
+
+    #[clap(long)]
+    /// Print the time and peak heap of each phase as a tree on stderr once the run ends
+    pub profile: bool,
+
+    #[clap(long, short, action = ArgAction::Count, global = true)]
+    /// Show solver diagnostics on stderr: -v for summaries, -vv for every step
+    pub verbose: u8,
+
+    #[clap(long, short, global = true, conflicts_with = "verbose")]
+    /// Show nothing but answers and errors
+    pub quiet: bool,
+
+    #[clap(long, global = true, default_value = "default", value_parser = profile_name)]
+    /// Whose inputs to use. Inputs are read from inputs/<name>/<year>/dayN.txt, falling back to data/
+    pub profile_name: String,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human readable lines, or a table with --all
+    Text,
+    /// One JSON object per line for each part solved
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check answers for the real inputs against a file of known answers
+    Verify(VerifyArgs),
+    /// Time parsing and solving over repeated runs
+    Bench(BenchArgs),
+    /// Re-run a day whenever its input or examples change
+    Watch(WatchArgs),
+    /// Write a random input for a day, in the puzzle's format
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[clap(long)]
+    /// TOML file of known answers, with a `[dayN]` table holding `part1` and `part2`.
+    /// Defaults to the profile's answers.toml, or answers.toml if the profile has none
+    pub answers: Option<String>,
+
+    #[clap(long, short)]
+    /// Day to verify. Can be repeated, and defaults to every day
+    pub day: Vec<Day>,
+
+    #[clap(long, short, value_enum, default_value_t = Part::Both)]
+    /// Part of the puzzle you wish to verify
+    pub part: Part,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[clap(long, short)]
+    /// Day to benchmark. Can be repeated, and defaults to every day
+    pub day: Vec<Day>,
+
+    #[clap(long, short, value_enum, default_value_t = Part::Both)]
+    /// Part of the puzzle you wish to benchmark
+    pub part: Part,
+
+    #[clap(long, short, default_value_t = 10)]
+    /// Number of timed runs
+    pub runs: usize,
+
+    #[clap(long, short, default_value_t = 1)]
+    /// Number of untimed runs before timing starts
+    pub warmup: usize,
+
+    #[clap(long)]
+    /// Save the median times to this file, for comparing against later
+    pub save: Option<String>,
+
+    #[clap(long, short)]
+    /// Compare against median times saved by an earlier --save
+    pub baseline: Option<String>,
+
+    #[clap(long, default_value_t = 10.0)]
+    /// Percentage slowdown against the baseline that counts as a regression
+    pub threshold: f64,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[clap(long, short)]
+    /// Day to run
+    pub day: Day,
+
+    #[clap(long, short, value_enum, default_value_t = Part::Both)]
+    /// Part of the puzzle you wish to run
+    pub part: Part,
+
+    #[clap(long, short)]
+    /// Input file to solve. Defaults to the day's file in data/
+    pub input: Option<String>,
+
+    #[clap(long, default_value_t = 500)]
+    /// Milliseconds between checks for changes
+    pub interval: u64,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
     #[clap(long, short)]
-    /// Day you wish to run
+    /// Day to generate an input for
     pub day: Day,
+
+    #[clap(long, default_value_t = 0)]
+    /// Seed for the random choices. The same seed and size always give the same input
+    pub seed: u64,
+
+    #[clap(long)]
+    /// How big to make the input. What it counts, such as lines or the width of a grid, depends
+    /// on the day
+    pub size: Option<usize>,
+
+    #[clap(long, short)]
+    /// File to write the input to. Defaults to stdout
+    pub output: Option<String>,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
-enum Day {
-    Day1,
-    Day2,
-    Day3,
-    Day4,
-    Day5,
-    Day6,
-    Day7,
-    Day8,
-    Day9,
-    Day10,
-    Day11,
-    Day12,
-    Day13,
-    Day14,
-    Day15,
-    Day16,
-    Day17,
-    Day18,
-    Day19,
-    Day20,
-    Day21,
-    Day22,
-    Day23,
-    Day24,
-    Day25,
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Part {
+    fn runs_one(&self) -> bool {
+        return *self != Part::Two;
+    }
+
+    fn runs_two(&self) -> bool {
+        return *self != Part::One;
+    }
+}
+
+struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// A solved part as printed by `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub answer: serde_json::Value,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub duration_ns: u64,
+    pub input: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<serde_json::Value>,
+}
+
+/// A day of the chosen year
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Day(usize);
+
+impl Day {
+    fn number(&self) -> usize {
+        return self.0;
+    }
+}
+
+impl FromStr for Day {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let number = s.strip_prefix("day").unwrap_or(s);
+
+        return match number.parse() {
+            Ok(day) if (1..=25).contains(&day) => Ok(Day(day)),
+            _ => Err("expected a day from 1 to 25, such as `5` or `day5`".to_string()),
+        };
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
-    let day = cli.day;
-
-    match day {
-        Day::Day1 => day1(),
-        Day::Day2 => day2(),
-        Day::Day3 => day3(),
-        Day::Day4 => day4(),
-        Day::Day5 => day5(),
-        Day::Day6 => day6(),
-        Day::Day7 => day7(),
-        Day::Day8 => day8(),
-        Day::Day9 => day9(),
-        Day::Day10 => day10(),
-        Day::Day11 => day11(),
-        Day::Day12 => day12(),
-        Day::Day13 => day13(),
-        Day::Day14 => day14(),
-        Day::Day15 => day15(),
-        Day::Day16 => day16(),
-        Day::Day17 => day17(),
-        Day::Day18 => day18(),
-        Day::Day19 => day19(),
-        Day::Day20 => day20(),
-        Day::Day21 => day21(),
-        Day::Day22 => day22(),
-        Day::Day23 => day23(),
-        Day::Day24 => day24(),
-        Day::Day25 => day25(),
-    }
-}
-
-fn read_lines(path: &str) -> Vec<String> {
-    // Attempt to open the file in read-only mode
-    let mut file = match File::open(path) {
-        Ok(file) => file,
+
+    init_logging(cli.verbose, cli.quiet);
+
+    let _ = YEAR.set(cli.year);
+    let _ = STORE.set(InputStore::new("inputs", &cli.profile_name, cli.year));
+
+    let mut days: Vec<Day> = cli.day.into_iter().collect();
+    match &cli.command {
+        Some(Command::Verify(args)) => days.extend(&args.day),
+        Some(Command::Bench(args)) => days.extend(&args.day),
+        Some(Command::Watch(args)) => days.push(args.day),
+        Some(Command::Generate(args)) => days.push(args.day),
+        None => {}
+    }
+
+    if let Some(day) = days
+        .iter()
+        .find(|d| find_solution(cli.year, d.number()).is_none())
+    {
+        let missing = compiled_out()
+            .into_iter()
+            .find(|(year, number, _)| *year == cli.year && *number == day.number());
+
+        match missing {
+            Some((_, _, feature)) => eprintln!(
+                "error: {} day {} is not in this build; rebuild with `--features {}` to run it",
+                cli.year,
+                day.number(),
+                feature
+            ),
+            None => eprintln!(
+                "error: there is no solution for {} day {}",
+                cli.year,
+                day.number()
+            ),
+        }
+        process::exit(1);
+    }
+
+    match &cli.command {
+        Some(Command::Verify(args)) => return verify(args),
+        Some(Command::Bench(args)) => return run_bench(args),
+        Some(Command::Watch(args)) => return watch(args),
+        Some(Command::Generate(args)) => return generate(args),
+        None => {}
+    }
+
+    // Spans share one peak counter, so tasks running at once would reset each other's peaks
+    if cli.profile && cli.jobs.is_some_and(|jobs| jobs != 1) {
+        eprintln!("error: --profile can only measure one task at a time; use --jobs 1");
+        process::exit(1);
+    }
+
+    if cli.profile {
+        profile::enable();
+    }
+
+    if cli.demo {
+        let days = match cli.day {
+            Some(day) => vec![day],
+            None => all_days(),
+        };
+
+        let failed = run_demos(&days, cli.part, cli.format);
+        return finish(failed);
+    }
+
+    if cli.all {
+        if let Some(jobs) = cli.jobs {
+            let failed = run_all_parallel(jobs, cli.part, cli.format);
+            return finish(failed);
+        }
+
+        let mut rows = vec![];
+        let mut failed = false;
+
+        for day in all_days() {
+            match run_day(day, &input_path(day.number(), None), cli.part) {
+                Ok(results) => {
+                    for (part, result) in results {
+                        match result {
+                            Ok(result) => rows.push((day.number(), result)),
+                            Err(e) => {
+                                eprintln!("error: day {} part {}: {}", day.number(), part, e);
+                                failed = true;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("error: day {}: {}", day.number(), e);
+                    failed = true;
+                }
+            }
+        }
+
+        match cli.format {
+            Format::Text => print_table(&rows),
+            Format::Json => {
+                for (day, result) in rows.iter() {
+                    print_record(*day, &input_path(*day, None), result, None);
+                }
+            }
+        }
+
+        return finish(failed);
+    }
+
+    // clap guarantees a day is given whenever --all is not
+    let day = cli.day.unwrap();
+
+    let path = &input_path(day.number(), cli.input.as_deref());
+
+    // Parsed once, since stdin can only be read once and rendering and animating need the input
+    // again
+    let span = profile::span(&format!("day {}", day.number()));
+    let solution = match load_day(day, path) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("error: day {}: {}", day.number(), e);
+            drop(span);
+            return finish(true);
+        }
+    };
+    let results = solve_day(&*solution, cli.part);
+    drop(span);
+
+    let mut failed = false;
+
+    for (part, result) in results {
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("error: day {} part {}: {}", day.number(), part, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        match cli.format {
+            Format::Text => println!(
+                "Day {} Part {}: {}",
+                day.number(),
+                result.part,
+                result.answer
+            ),
+            Format::Json => print_record(day.number(), path, &result, None),
+        }
+    }
+
+    if let Some(file) = &cli.render {
+        match render_day(&*solution, file) {
+            Ok(true) => {}
+            Ok(false) => {
+                eprintln!("error: day {} cannot be rendered", day.number());
+                return finish(true);
+            }
+            Err(e) => {
+                eprintln!("error: day {}: {}", day.number(), e);
+                return finish(true);
+            }
+        }
+    }
+
+    if cli.animate {
+        let frames = match animate_day(&*solution) {
+            Ok(Some(frames)) => frames,
+            Ok(None) => {
+                eprintln!("error: day {} cannot be animated", day.number());
+                return finish(true);
+            }
+            Err(e) => {
+                eprintln!("error: day {}: {}", day.number(), e);
+                return finish(true);
+            }
+        };
+
+        if let Err(e) = Player::new(frames, cli.fps).run() {
+            eprintln!("error: could not play the animation: {}", e);
+            return finish(true);
+        }
+    }
+
+    finish(failed);
+}
+
+/// Print the profile if one was asked for, then exit with an error status if anything failed
+fn finish(failed: bool) {
+    if profile::is_enabled() {
+        eprintln!();
+        eprint!("{}", profile::report());
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Solvers log through the `log` facade. `RUST_LOG` overrides the level set by the flags
+fn init_logging(verbose: u8, quiet: bool) {
+    let logger = env_logger::Builder::new()
+        .filter_level(LevelFilter::Warn)
+        .filter_module("advent_of_code_2023", level_for(verbose, quiet))
+        .filter_module(module_path!(), level_for(verbose, quiet))
+        .format_timestamp(None)
+        .parse_default_env()
+        .build();
+
+    log::set_max_level(logger.filter());
+    log::set_boxed_logger(Box::new(CapturingLogger { inner: logger }))
+        .expect("The logger is only set once");
+}
+
+fn level_for(verbose: u8, quiet: bool) -> LevelFilter {
+    return match (quiet, verbose) {
+        (true, _) => LevelFilter::Error,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    };
+}
+
+thread_local! {
+    /// Output of the tasks running on this thread, innermost last. A task waiting on rayon
+    /// can run another task on the same thread, which pushes and pops its own buffer
+    static CAPTURED: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// Sends log records to the running task's buffer when there is one, and to stderr otherwise
+struct CapturingLogger {
+    inner: env_logger::Logger,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        return self.inner.enabled(metadata);
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.inner.matches(record) {
+            return;
+        }
+
+        let line = format!("[{} {}] {}", record.level(), record.target(), record.args());
+        if !capture(&line) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Add a line to the running task's output, returning false if there is no task to take it
+fn capture(line: &str) -> bool {
+    return CAPTURED.with(|captured| match captured.borrow_mut().last_mut() {
+        Some(buffer) => {
+            buffer.push_str(line);
+            buffer.push('\n');
+            true
+        }
+        None => false,
+    });
+}
+
+/// One day and part solved on the thread pool
+struct Task {
+    pub day: usize,
+    pub part: u8,
+    pub result: std::result::Result<Option<PartResult>, String>,
+    pub output: String,
+    pub time: Duration,
+}
+
+/// Returns whether any task errored or panicked
+fn run_all_parallel(jobs: usize, part: Part, format: Format) -> bool {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("Failed to create the thread pool");
+
+    let mut tasks = vec![];
+    for day in all_days() {
+        if part.runs_one() {
+            tasks.push((day, Part::One));
+        }
+        if part.runs_two() {
+            tasks.push((day, Part::Two));
+        }
+    }
+
+    // Panics become the task's error, with the message kept in its output
+    let previous_hook = Arc::new(panic::take_hook());
+    let hook = Arc::clone(&previous_hook);
+    panic::set_hook(Box::new(move |info| {
+        if !capture(&info.to_string()) {
+            hook(info);
+        }
+    }));
+
+    let now = Instant::now();
+    let tasks: Vec<Task> = pool.install(|| {
+        return tasks
+            .par_iter()
+            .map(|&(day, part)| run_task(day, part))
+            .collect();
+    });
+    let wall = now.elapsed();
+
+    // Dropping our hook leaves the previous one with no other owner, so it can be put back
+    drop(panic::take_hook());
+    if let Ok(previous_hook) = Arc::try_unwrap(previous_hook) {
+        panic::set_hook(previous_hook);
+    }
+
+    let cpu: Duration = tasks.iter().map(|t| t.time).sum();
+
+    for task in tasks.iter().filter(|t| !t.output.is_empty()) {
+        eprintln!("--- day {} part {} ---", task.day, task.part);
+        eprint!("{}", task.output);
+    }
+
+    let mut rows = vec![];
+    let mut failed = false;
+
+    for task in tasks {
+        match task.result {
+            Ok(Some(result)) => rows.push((task.day, result)),
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("error: day {} part {}: {}", task.day, task.part, e);
+                failed = true;
+            }
+        }
+    }
+
+    match format {
+        Format::Text => print_table(&rows),
+        Format::Json => {
+            for (day, result) in rows.iter() {
+                print_record(*day, &input_path(*day, None), result, None);
+            }
+        }
+    }
+
+    eprintln!(
+        "Wall time: {:.2?}, summed CPU time: {:.2?} ({:.1}x on {} threads)",
+        wall,
+        cpu,
+        cpu.as_secs_f64() / wall.as_secs_f64(),
+        pool.current_num_threads()
+    );
+
+    return failed;
+}
+
+/// Read, parse and solve one part, keeping its log output and any panic to itself
+fn run_task(day: Day, part: Part) -> Task {
+    CAPTURED.with(|captured| captured.borrow_mut().push(String::new()));
+
+    let now = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_day(day, &input_path(day.number(), None), part)
+    }));
+    let time = now.elapsed();
+
+    let output = CAPTURED
+        .with(|captured| captured.borrow_mut().pop())
+        .unwrap_or_default();
+
+    let result = match result {
+        Ok(Ok(mut results)) => match results.pop() {
+            Some((_, Ok(result))) => Ok(Some(result)),
+            Some((_, Err(e))) => Err(e.to_string()),
+            None => Ok(None),
+        },
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(&*payload))),
+    };
+
+    return Task {
+        day: day.number(),
+        part: if part == Part::One { 1 } else { 2 },
+        result,
+        output,
+        time,
+    };
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message;
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message;
+    }
+
+    return "unknown panic";
+}
+
+fn verify(args: &VerifyArgs) {
+    let answers_path = match &args.answers {
+        Some(path) => path.clone(),
+        None => {
+            let path = store().answers_path();
+            if Path::new(&path).exists() {
+                path
+            } else {
+                "answers.toml".to_string()
+            }
+        }
+    };
+
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
         Err(e) => {
-            panic!("Error opening file: {}", e);
+            eprintln!("error: answers file `{}`: {}", answers_path, e);
+            process::exit(1);
+        }
+    };
+
+    let days = if args.day.is_empty() {
+        all_days()
+    } else {
+        args.day.clone()
+    };
+
+    let mut rows = vec![];
+    let mut errors = 0;
+
+    for day in days {
+        let path = input_path(day.number(), None);
+
+        match run_day(day, &path, args.part) {
+            Ok(results) => {
+                let mut solved = vec![];
+                let mut errored = false;
+                for (part, result) in results {
+                    match result {
+                        Ok(result) => solved.push(result),
+                        Err(e) => {
+                            eprintln!("error: day {} part {}: {}", day.number(), part, e);
+                            errored = true;
+                            errors += 1;
+                        }
+                    }
+                }
+
+                let verdicts: Vec<Verdict> = solved
+                    .iter()
+                    .map(|r| answers.check(day.number(), r.part, &r.answer))
+                    .collect();
+
+                if !errored && !verdicts.is_empty() && verdicts.iter().all(|v| *v == Verdict::Pass)
+                {
+                    record_checksum(day.number(), &path);
+                }
+
+                rows.extend(
+                    solved
+                        .into_iter()
+                        .zip(verdicts)
+                        .map(|(r, v)| (day.number(), r, v)),
+                );
+            }
+            Err(e) => {
+                eprintln!("error: day {}: {}", day.number(), e);
+                errors += 1;
+            }
+        }
+    }
+
+    for (day, result, verdict) in rows.iter() {
+        let status = match verdict {
+            Verdict::Pass => "pass".to_string(),
+            Verdict::Fail { expected } => format!("FAIL (expected {})", expected),
+            Verdict::Missing => "missing".to_string(),
+        };
+
+        println!(
+            "Day {:>2} Part {}: {:<20} {}",
+            day,
+            result.part,
+            result.answer.to_string(),
+            status
+        );
+    }
+
+    let count = |f: fn(&Verdict) -> bool| rows.iter().filter(|(_, _, v)| f(v)).count();
+    let passed = count(|v| *v == Verdict::Pass);
+    let failed = count(|v| matches!(v, Verdict::Fail { .. }));
+    let missing = count(|v| *v == Verdict::Missing);
+
+    println!(
+        "{} passed, {} failed, {} missing, {} errored",
+        passed, failed, missing, errors
+    );
+
+    if failed > 0 || errors > 0 {
+        process::exit(1);
+    }
+}
+
+fn run_bench(args: &BenchArgs) {
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("error: baseline `{}`: {}", path, e);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    let days = if args.day.is_empty() {
+        all_days()
+    } else {
+        args.day.clone()
+    };
+
+    let mut parts = vec![];
+    if args.part.runs_one() {
+        parts.push(Stage::Part1);
+    }
+    if args.part.runs_two() {
+        parts.push(Stage::Part2);
+    }
+
+    let mut saved = Baseline::default();
+    let mut failed = false;
+    let mut regressions = 0;
+
+    println!(
+        "{:>3} | {:>6} | {:>10} | {:>10} | {:>10} | {:>10} | vs baseline",
+        "Day", "Stage", "Min", "Median", "Mean", "Stddev"
+    );
+    println!("{}", "-".repeat(80));
+
+    for day in days {
+        let registration = registration(day);
+        let path = input_path(day.number(), None);
+
+        let results = match bench::run(registration, &path, &parts, args.warmup, args.runs) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("error: day {}: {}", day.number(), e);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (stage, stats) in results {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(e) => {
+                    eprintln!("error: day {} {}: {}", day.number(), stage, e);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let comparison = match baseline
+                .as_ref()
+                .and_then(|b| b.median(day.number(), stage))
+            {
+                Some(before) => {
+                    let change = bench::change(before, stats.median);
+                    if change > args.threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => "-".to_string(),
+            };
+
+            println!(
+                "{:>3} | {:>6} | {:>10} | {:>10} | {:>10} | {:>10} | {}",
+                day.number(),
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+                comparison
+            );
+
+            saved.insert(day.number(), stage, &stats);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(e) = saved.save(path) {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{} stages are more than {}% slower than the baseline",
+            regressions, args.threshold
+        );
+    }
+
+    if failed || regressions > 0 {
+        process::exit(1);
+    }
+}
+
+/// Write a generated input to the output file, or to stdout
+fn generate(args: &GenerateArgs) {
+    let registration = registration(args.day);
+    let mut generator = Generator::new(args.seed, args.size);
+    let input = (registration.generate)(&mut generator) + "\n";
+
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = fs::write(path, input) {
+                eprintln!("error: could not write `{}`: {}", path, e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", input),
+    }
+}
+
+fn watch(args: &WatchArgs) {
+    let registration = registration(args.day);
+    let path = &input_path(args.day.number(), args.input.as_deref());
+
+    if path == "-" {
+        eprintln!("error: watch needs an input file, not stdin");
+        process::exit(1);
+    }
+
+    let demos: Vec<&Demo> = registration
+        .demos
+        .iter()
+        .filter(|d| (d.part == 1 && args.part.runs_one()) || (d.part == 2 && args.part.runs_two()))
+        .collect();
+
+    let mut paths = vec![path.to_string()];
+    for demo in demos.iter() {
+        if !paths.iter().any(|p| p == demo.input) {
+            paths.push(demo.input.to_string());
+        }
+    }
+
+    let mut watcher = Watcher::new(&paths);
+    eprintln!(
+        "Watching {} every {}ms",
+        watcher.paths().collect::<Vec<_>>().join(", "),
+        args.interval
+    );
+
+    let mut previous = HashMap::new();
+
+    loop {
+        run_watched(args, registration, path, &demos, &mut previous);
+
+        let changed = loop {
+            thread::sleep(Duration::from_millis(args.interval));
+
+            let changed = watcher.changed();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        // Wait for the writes to settle, so a file is not solved half written
+        loop {
+            thread::sleep(Duration::from_millis(args.interval));
+
+            if watcher.changed().is_empty() {
+                break;
+            }
+        }
+
+        println!();
+        eprintln!("Changed: {}", changed.join(", "));
+    }
+}
+
+/// Solve the input and the examples, printing each answer and time against the previous run
+fn run_watched(
+    args: &WatchArgs,
+    registration: &Registration,
+    path: &str,
+    demos: &[&Demo],
+    previous: &mut HashMap<(String, u8), PartResult>,
+) {
+    match run_day(args.day, path, args.part) {
+        Ok(results) => {
+            for (part, result) in results {
+                match result {
+                    Ok(result) => print_watched(args.day, path, result, None, previous),
+                    Err(e) => eprintln!(
+                        "error: day {} part {} ({}): {}",
+                        args.day.number(),
+                        part,
+                        path,
+                        e
+                    ),
+                }
+            }
+        }
+        Err(e) => eprintln!("error: day {} ({}): {}", args.day.number(), path, e),
+    }
+
+    for demo in demos {
+        match run_demo(registration, demo) {
+            Ok(result) => print_watched(args.day, demo.input, result, Some(demo), previous),
+            Err(e) => eprintln!("error: day {} ({}): {}", args.day.number(), demo.input, e),
+        }
+    }
+}
+
+fn print_watched(
+    day: Day,
+    path: &str,
+    result: PartResult,
+    demo: Option<&Demo>,
+    previous: &mut HashMap<(String, u8), PartResult>,
+) {
+    let before = previous.get(&(path.to_string(), result.part));
+
+    let answer = match before {
+        Some(before) if before.answer == result.answer => format!("{} (unchanged)", result.answer),
+        Some(before) => format!("{} (was {})", result.answer, before.answer),
+        None => result.answer.to_string(),
+    };
+
+    let time = match before {
+        Some(before) => format!(
+            "{:.2?} (was {:.2?}, {:+.1}%)",
+            result.time,
+            before.time,
+            bench::change(before.time, result.time)
+        ),
+        None => format!("{:.2?}", result.time),
+    };
+
+    let expected = match demo {
+        Some(demo) if demo.expected != result.answer => {
+            format!(" MISMATCH, expected {}", demo.expected)
+        }
+        _ => String::new(),
+    };
+
+    println!(
+        "Day {} Part {}: {}{} in {} [{}]",
+        day.number(),
+        result.part,
+        answer,
+        expected,
+        time,
+        path
+    );
+
+    previous.insert((path.to_string(), result.part), result);
+}
+
+/// Returns whether any example errored or gave the wrong answer
+fn run_demos(days: &[Day], part: Part, format: Format) -> bool {
+    let mut failed = false;
+
+    for day in days {
+        let registration = registration(*day);
+
+        let demos: Vec<&Demo> = registration
+            .demos
+            .iter()
+            .filter(|d| (d.part == 1 && part.runs_one()) || (d.part == 2 && part.runs_two()))
+            .collect();
+
+        if demos.is_empty() {
+            eprintln!("day {}: there is no example for this part", day.number());
+            continue;
+        }
+
+        for demo in demos {
+            let result = match run_demo(registration, demo) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("error: day {} ({}): {}", day.number(), demo.input, e);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let matches = result.answer == demo.expected;
+            if !matches {
+                failed = true;
+            }
+
+            match format {
+                Format::Text => println!(
+                    "Day {} Part {}: {} (expected {}){} [{}]",
+                    day.number(),
+                    result.part,
+                    result.answer,
+                    demo.expected,
+                    if matches { "" } else { " MISMATCH" },
+                    demo.input
+                ),
+                Format::Json => {
+                    print_record(day.number(), demo.input, &result, Some(&demo.expected))
+                }
+            }
         }
+    }
+
+    return failed;
+}
+
+fn run_demo(registration: &Registration, demo: &Demo) -> Result<PartResult> {
+    let _span = profile::span(&format!("day {}", registration.day));
+
+    let input = Input::open(demo.input)?;
+    let solution = {
+        let _span = profile::span("parse");
+        (registration.parse)(input.text())?
+    };
+
+    if demo.part == 1 {
+        return time_part(1, || solution.part1_demo());
+    }
+
+    return time_part(2, || solution.part2_demo());
+}
+
+fn year_number(s: &str) -> std::result::Result<u16, String> {
+    let years = years();
+
+    return match s.parse() {
+        Ok(year) if years.contains(&year) => Ok(year),
+        _ => {
+            let years: Vec<String> = years.iter().map(|y| y.to_string()).collect();
+            Err(format!("there are solutions for {}", years.join(", ")))
+        }
+    };
+}
+
+fn image_path(path: &str) -> std::result::Result<String, String> {
+    if !render::supports(path) {
+        return Err(format!(
+            "expected a file ending in .{}",
+            render::EXTENSIONS.join(", .")
+        ));
+    }
+
+    return Ok(path.to_string());
+}
+
+fn year() -> u16 {
+    return *YEAR.get().expect("The year is chosen before any day runs");
+}
+
+/// Every day of the chosen year that has a solution, warning about any left out of this build
+fn all_days() -> Vec<Day> {
+    for (_, day, feature) in compiled_out().iter().filter(|(y, _, _)| *y == year()) {
+        warn!(
+            "skipping day {}, which needs the `{}` feature in this build",
+            day, feature
+        );
+    }
+
+    return solutions()
+        .iter()
+        .filter(|r| r.year == year())
+        .map(|r| Day(r.day))
+        .collect();
+}
+
+/// The solution for a day of the chosen year. Days given on the command line are checked up front
+fn registration(day: Day) -> &'static Registration {
+    return find_solution(year(), day.number()).expect("Every day run has a solution");
+}
+
+fn profile_name(name: &str) -> std::result::Result<String, String> {
+    if !store::valid_profile_name(name) {
+        return Err("use only letters, digits, `-` and `_`".to_string());
+    }
+
+    return Ok(name.to_string());
+}
+
+fn store() -> &'static InputStore {
+    return STORE
+        .get()
+        .expect("The store is set up before any day runs");
+}
+
+/// The file a day reads: the one given, else the profile's input, else the day's file in data/
+fn input_path(day: usize, input: Option<&str>) -> String {
+    if let Some(path) = input {
+        return path.to_string();
+    }
+
+    if let Some(path) = store().find_input(day) {
+        return path;
+    }
+
+    return registration(Day(day)).default_input.to_string();
+}
+
+/// Warn when a profile's input no longer matches the one its answers were verified against
+fn check_checksum(day: usize, path: &str, input: &str) {
+    if path != store().input_path(day) {
+        return;
+    }
+
+    match store().check(day, input) {
+        Ok(Checksum::Changed) => warn!(
+            "day {}: `{}` has changed since its answers were verified for profile `{}`",
+            day,
+            path,
+            store().profile()
+        ),
+        Ok(_) => {}
+        Err(e) => warn!("day {}: could not check the input's checksum: {}", day, e),
+    }
+}
+
+/// Remember the checksum of a profile's input once its answers have been verified
+fn record_checksum(day: usize, path: &str) {
+    if path != store().input_path(day) {
+        return;
+    }
+
+    let result = read_input(path).and_then(|input| store().record(day, &input));
+    if let Err(e) = result {
+        warn!("day {}: could not record the input's checksum: {}", day, e);
+    }
+}
+
+/// Read, parse and solve the day's input
+fn run_day(day: Day, path: &str, part: Part) -> Result<Vec<(u8, Result<PartResult>)>> {
+    let _span = profile::span(&format!("day {}", day.number()));
+
+    let solution = load_day(day, path)?;
+
+    return Ok(solve_day(&*solution, part));
+}
+
+/// Read and parse the day's input
+fn load_day(day: Day, path: &str) -> Result<Box<dyn Solution>> {
+    let input = Input::open(path)?;
+    check_checksum(day.number(), path, input.text());
+
+    let _span = profile::span("parse");
+    return (registration(day).parse)(input.text());
+}
+
+/// Solve each part asked for. A part that errors keeps its error next to it, so it does not hide
+/// the other part's answer
+fn solve_day(solution: &dyn Solution, part: Part) -> Vec<(u8, Result<PartResult>)> {
+    let mut results = vec![];
+
+    if part.runs_one() {
+        results.push((1, time_part(1, || solution.part1())));
+    }
+
+    if part.runs_two() {
+        results.push((2, time_part(2, || solution.part2())));
+    }
+
+    // Skip parts that do not exist, such as day 25 part 2
+    results.retain(|(_, r)| !matches!(r, Ok(r) if r.answer == Answer::None));
+
+    return results;
+}
+
+/// Draws the day's input to `file`. False if the day has no picture to draw
+fn render_day(solution: &dyn Solution, file: &str) -> Result<bool> {
+    let _span = profile::span("render");
+
+    return match solution.render()? {
+        Some(picture) => picture.save(file).map(|_| true),
+        None => Ok(false),
+    };
+}
+
+/// Steps through the day's simulation, keeping every frame. None if the day has no simulation
+fn animate_day(solution: &dyn Solution) -> Result<Option<Vec<Frame>>> {
+    let _span = profile::span("animate");
+
+    let mut frames = vec![];
+
+    return match solution.animate(&mut frames)? {
+        true => Ok(Some(frames)),
+        false => Ok(None),
     };
+}
+
+fn time_part(part: u8, solve: impl FnOnce() -> Result<Answer>) -> Result<PartResult> {
+    let _span = profile::span(&format!("part {}", part));
+    let now = Instant::now();
+    let answer = solve()?;
+    let time = now.elapsed();
 
-    // Create a buffer to hold the file contents
-    let mut contents = String::new();
+    return Ok(PartResult { part, answer, time });
+}
 
-    // Read the file contents into the buffer
-    file.read_to_string(&mut contents).unwrap();
+fn print_record(day: usize, path: &str, result: &PartResult, expected: Option<&Answer>) {
+    let (answer, kind) = answer_json(&result.answer);
 
-    let split: Vec<String> = contents
-        .split("\n")
-        .into_iter()
-        .map(|c| c.to_string())
+    let record = Record {
+        year: year(),
+        day,
+        part: result.part,
+        answer,
+        kind,
+        duration_ns: result.time.as_nanos() as u64,
+        input: path,
+        expected: expected.map(|e| answer_json(e).0),
+    };
+
+    println!(
+        "{}",
+        serde_json::to_string(&record).expect("Records always serialise")
+    );
+}
+
+fn answer_json(answer: &Answer) -> (serde_json::Value, &'static str) {
+    return match answer {
+        Answer::Integer(x) => (serde_json::Value::from(*x), "integer"),
+        Answer::Float(x) => (serde_json::Value::from(*x), "float"),
+        Answer::None => (serde_json::Value::Null, "none"),
+    };
+}
+
+fn print_table(rows: &[(usize, PartResult)]) {
+    let times: Vec<String> = rows
+        .iter()
+        .map(|(_, r)| format!("{:.2?}", r.time))
         .collect();
 
-    return split;
+    let answer_width = rows
+        .iter()
+        .map(|(_, r)| r.answer.to_string().len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+    let time_width = times
+        .iter()
+        .map(|t| t.len())
+        .chain(["Time".len()])
+        .max()
+        .unwrap();
+
+    println!(
+        "{:>3} | {:>4} | {:>aw$} | {:>tw$}",
+        "Day",
+        "Part",
+        "Answer",
+        "Time",
+        aw = answer_width,
+        tw = time_width
+    );
+    println!(
+        "{}-+-{}-+-{}-+-{}",
+        "-".repeat(3),
+        "-".repeat(4),
+        "-".repeat(answer_width),
+        "-".repeat(time_width)
+    );
+
+    for ((day, result), time) in rows.iter().zip(times.iter()) {
+        println!(
+            "{:>3} | {:>4} | {:>aw$} | {:>tw$}",
+            day,
+            result.part,
+            result.answer,
+            time,
+            aw = answer_width,
+            tw = time_width
+        );
+    }
+
+    let total: Duration = rows.iter().map(|(_, r)| r.time).sum();
+    println!("Total time: {:.2?}", total);
 }
@@ -0,0 +1,504 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{grid::Grid, input, Error, Result};
+
+/// One line of input and how much of it is left to parse
+#[derive(Clone, Copy)]
+pub struct Cursor<'a> {
+    line_index: usize,
+    line: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line_index: usize, line: &'a str) -> Self {
+        return Cursor {
+            line_index,
+            line,
+            rest: line,
+        };
+    }
+
+    /// The part of the line not parsed yet
+    pub fn rest(&self) -> &'a str {
+        return self.rest;
+    }
+
+    /// 0-based column of the next character to parse
+    pub fn column(&self) -> usize {
+        return self.line.len() - self.rest.len();
+    }
+
+    /// A parse error at the next character to parse
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        return Error::parse(self.line_index, self.column(), message);
+    }
+
+    /// A parse error at `part`, which must be a slice of the line
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> Error {
+        return Error::parse_at(self.line_index, self.line, part, message);
+    }
+
+    /// Fails unless only spaces are left on the line
+    pub fn finish(&self) -> Result<()> {
+        let rest = self.rest.trim_end();
+        if rest.is_empty() {
+            return Ok(());
+        }
+
+        let rest = rest.trim_start();
+        return Err(self.error_at(
+            rest,
+            format!("expected the end of the line, found `{}`", rest),
+        ));
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        return taken;
+    }
+
+    /// Describes what comes next, for error messages
+    fn found(&self) -> String {
+        return match self.rest.split_whitespace().next() {
+            Some(word) => format!("`{}`", word),
+            None => "the end of the line".to_string(),
+        };
+    }
+}
+
+/// Parses a `T` from the front of a cursor, moving it past what was parsed
+pub trait Parser<'a, T>: Fn(&mut Cursor<'a>) -> Result<T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&mut Cursor<'a>) -> Result<T> {}
+
+/// Parses the whole of `line`, line `line_index` of the input, with `parser`
+pub fn parse_line<'a, T>(
+    line_index: usize,
+    line: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<T> {
+    let mut cursor = Cursor::new(line_index, line);
+    let value = parser(&mut cursor)?;
+    cursor.finish()?;
+
+    return Ok(value);
+}
+
+/// Parses every line of `input` with `parser`
+pub fn parse_lines<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<Vec<T>> {
+    return input::lines(input)
+        .enumerate()
+        .map(|(i, line)| parse_line(i, line, &parser))
+        .collect();
+}
+
+/// Exactly the text `expected`
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    return move |cursor: &mut Cursor<'a>| {
+        if !cursor.rest.starts_with(expected) {
+            return Err(cursor.error(format!("expected `{}`, found {}", expected, cursor.found())));
+        }
+
+        return Ok(cursor.take(expected.len()));
+    };
+}
+
+/// One or more characters matching `predicate`. `what` describes them in errors
+pub fn take_while<'a>(
+    what: &'static str,
+    predicate: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    return move |cursor: &mut Cursor<'a>| {
+        let len = cursor
+            .rest
+            .find(|c| !predicate(c))
+            .unwrap_or(cursor.rest.len());
+
+        if len == 0 {
+            return Err(cursor.error(format!("expected {}, found {}", what, cursor.found())));
+        }
+
+        return Ok(cursor.take(len));
+    };
+}
+
+/// One value per character up to the next space, each made by `cell`
+pub fn cells<'a, T>(cell: impl Fn(char) -> Option<T>) -> impl Parser<'a, Vec<T>> {
+    return move |cursor: &mut Cursor<'a>| {
+        let mut cells = vec![];
+
+        for (j, c) in cursor.rest.char_indices() {
+            if c == ' ' {
+                break;
+            }
+
+            match cell(c) {
+                Some(x) => cells.push(x),
+                None => {
+                    let unexpected = &cursor.rest[j..];
+                    return Err(
+                        cursor.error_at(unexpected, format!("unexpected character `{}`", c))
+                    );
+                }
+            }
+        }
+
+        if cells.is_empty() {
+            return Err(cursor.error(format!("expected a character, found {}", cursor.found())));
+        }
+
+        let len = cursor.rest.find(' ').unwrap_or(cursor.rest.len());
+        cursor.take(len);
+
+        return Ok(cells);
+    };
+}
+
+/// A name made of letters, digits and underscores
+pub fn identifier<'a>() -> impl Parser<'a, &'a str> {
+    return take_while("a name", |c| c.is_alphanumeric() || c == '_');
+}
+
+/// One or more spaces
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    return map(take_while("a space", |c| c == ' '), |_| ());
+}
+
+/// Any number of spaces, including none
+pub fn optional_spaces<'a>() -> impl Parser<'a, ()> {
+    return |cursor: &mut Cursor<'a>| {
+        cursor.rest = cursor.rest.trim_start_matches(' ');
+        return Ok(());
+    };
+}
+
+/// An unsigned decimal integer
+pub fn integer<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    return |cursor: &mut Cursor<'a>| number(cursor, false);
+}
+
+/// A decimal integer with an optional `-` or `+` sign
+pub fn signed<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    return |cursor: &mut Cursor<'a>| number(cursor, true);
+}
+
+fn number<T>(cursor: &mut Cursor, signed: bool) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let sign = match cursor.rest.as_bytes().first() {
+        Some(b'-' | b'+') if signed => 1,
+        _ => 0,
+    };
+
+    let digits = cursor.rest[sign..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+
+    if digits == 0 {
+        return Err(cursor.error(format!("expected a number, found {}", cursor.found())));
+    }
+
+    let start = *cursor;
+    let text = cursor.take(sign + digits);
+
+    return text
+        .parse::<T>()
+        .map_err(|e| start.error(format!("invalid number `{}`: {}", text, e)));
+}
+
+/// One or more signed integers separated by spaces, ignoring spaces before the first. Stops
+/// before anything that does not start a number
+pub fn numbers<'a, T>() -> impl Parser<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    return |cursor: &mut Cursor<'a>| {
+        let mut numbers = vec![preceded(optional_spaces(), signed())(cursor)?];
+
+        loop {
+            let mut next = *cursor;
+            optional_spaces()(&mut next)?;
+
+            let mut chars = next.rest.chars();
+            let starts_number = match chars.next() {
+                Some('-' | '+') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+                Some(c) => c.is_ascii_digit(),
+                None => false,
+            };
+
+            if !starts_number || next.column() == cursor.column() {
+                return Ok(numbers);
+            }
+
+            numbers.push(signed()(&mut next)?);
+            *cursor = next;
+        }
+    };
+}
+
+/// One or more `item`s with `separator` between them. Once a separator is found, the next item
+/// must follow it
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    return move |cursor: &mut Cursor<'a>| {
+        let mut items = vec![item(cursor)?];
+
+        loop {
+            let mut next = *cursor;
+            if separator(&mut next).is_err() {
+                return Ok(items);
+            }
+
+            *cursor = next;
+            items.push(item(cursor)?);
+        }
+    };
+}
+
+/// A `key`, then `separator`, then a `value`
+pub fn key_value<'a, K, S, V>(
+    key: impl Parser<'a, K>,
+    separator: impl Parser<'a, S>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    return move |cursor: &mut Cursor<'a>| {
+        let key = key(cursor)?;
+        separator(cursor)?;
+        let value = value(cursor)?;
+
+        return Ok((key, value));
+    };
+}
+
+/// `first` followed by `second`
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    return move |cursor: &mut Cursor<'a>| {
+        let first = first(cursor)?;
+        let second = second(cursor)?;
+
+        return Ok((first, second));
+    };
+}
+
+/// `parser`, after `prefix`
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    return move |cursor: &mut Cursor<'a>| {
+        prefix(cursor)?;
+        return parser(cursor);
+    };
+}
+
+/// `parser`, followed by `suffix`
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    return move |cursor: &mut Cursor<'a>| {
+        let value = parser(cursor)?;
+        suffix(cursor)?;
+
+        return Ok(value);
+    };
+}
+
+/// `parser`, between `open` and `close`
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    return preceded(open, terminated(parser, close));
+}
+
+/// `parser` if it succeeds, and nothing otherwise
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    return move |cursor: &mut Cursor<'a>| {
+        let mut next = *cursor;
+
+        return match parser(&mut next) {
+            Ok(value) => {
+                *cursor = next;
+                Ok(Some(value))
+            }
+            Err(_) => Ok(None),
+        };
+    };
+}
+
+/// Whatever is left of the line
+pub fn rest<'a>() -> impl Parser<'a, &'a str> {
+    return |cursor: &mut Cursor<'a>| Ok(cursor.take(cursor.rest.len()));
+}
+
+/// Transforms what `parser` parsed
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    return move |cursor: &mut Cursor<'a>| Ok(f(parser(cursor)?));
+}
+
+/// Transforms what `parser` parsed, reporting `f`'s error at the start of it
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> std::result::Result<U, String>,
+) -> impl Parser<'a, U> {
+    return move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        return f(parser(cursor)?).map_err(|message| start.error(message));
+    };
+}
+
+/// A grid with one cell per character of `lines`, the first of which is line `first_line` of
+/// the input. Every line must be as long as the first
+pub fn grid<T, S: AsRef<str>>(
+    first_line: usize,
+    lines: &[S],
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Grid<T>> {
+    let mut rows: Vec<Vec<T>> = vec![];
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut row = vec![];
+
+        for (j, c) in line.as_ref().chars().enumerate() {
+            match cell(c) {
+                Some(x) => row.push(x),
+                None => {
+                    return Err(Error::parse(
+                        first_line + i,
+                        j,
+                        format!("unexpected character `{}`", c),
+                    ))
+                }
+            }
+        }
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Error::parse(
+                    first_line + i,
+                    row.len(),
+                    format!("expected {} columns, found {}", first.len(), row.len()),
+                ));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    return Grid::from_rows(rows);
+}
+
+#[test]
+fn test_numbers() {
+    assert_eq!(parse_line(0, "12", integer::<u8>()).unwrap(), 12);
+    assert_eq!(parse_line(0, "-12", signed::<i64>()).unwrap(), -12);
+    assert_eq!(
+        parse_line(0, "  1 -2  3", numbers::<i64>()).unwrap(),
+        vec![1, -2, 3]
+    );
+
+    let error = parse_line(0, "300", integer::<u8>()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 1: invalid number `300`: number too large to fit in target type"
+    );
+
+    let error = parse_line(2, "1 2 x", numbers::<i64>()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 3, column 5: expected the end of the line, found `x`"
+    );
+}
+
+#[test]
+fn test_combinators() {
+    let card = pair(
+        delimited(pair(tag("Card"), spaces()), integer::<u32>(), tag(":")),
+        separated(numbers::<u32>(), tag(" |")),
+    );
+
+    let (id, lists) = parse_line(0, "Card  3: 1 21 | 4  5", &card).unwrap();
+    assert_eq!(id, 3);
+    assert_eq!(lists, vec![vec![1, 21], vec![4, 5]]);
+
+    let error = parse_line(0, "Card 3: 1 | 4 |", &card).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 16: expected a number, found the end of the line"
+    );
+
+    let rating = key_value(identifier(), tag("="), integer::<i64>());
+    let ratings = delimited(tag("{"), separated(rating, tag(",")), tag("}"));
+    assert_eq!(
+        parse_line(0, "{x=1,m=20}", ratings).unwrap(),
+        vec![("x", 1), ("m", 20)]
+    );
+
+    let turn = try_map(rest(), |s: &str| match s {
+        "L" => Ok(true),
+        "R" => Ok(false),
+        _ => Err(format!("unknown turn `{}`", s)),
+    });
+    assert_eq!(
+        parse_line(0, "X", turn).unwrap_err().to_string(),
+        "line 1, column 1: unknown turn `X`"
+    );
+
+    assert_eq!(
+        parse_line(0, "ab", opt(tag("a"))).unwrap_err().to_string(),
+        "line 1, column 2: expected the end of the line, found `b`"
+    );
+}
+
+#[test]
+fn test_cells() {
+    let springs = pair(
+        terminated(cells(|c| "#.?".contains(c).then_some(c)), spaces()),
+        separated(integer::<usize>(), tag(",")),
+    );
+
+    assert_eq!(
+        parse_line(0, "#.? 1,2", &springs).unwrap(),
+        (vec!['#', '.', '?'], vec![1, 2])
+    );
+    assert_eq!(
+        parse_line(0, "#.x 1,2", &springs).unwrap_err().to_string(),
+        "line 1, column 3: unexpected character `x`"
+    );
+}
+
+#[test]
+fn test_grid() {
+    let error = grid(4, &["..", ".#."], |c| (c != 'x').then_some(c)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 6, column 4: expected 2 columns, found 3"
+    );
+
+    let error = grid(4, &["..", "x."], |c| (c != 'x').then_some(c)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 6, column 1: unexpected character `x`"
+    );
+}
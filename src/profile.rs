@@ -0,0 +1,324 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::RefCell,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicBool, AtomicIsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated less bytes freed while profiling, which goes below zero when heap allocated
+/// before profiling began is freed
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+static NODES: Mutex<Vec<Node>> = Mutex::new(vec![]);
+
+thread_local! {
+    /// Indices into `NODES` of the spans open on this thread, innermost last
+    static STACK: RefCell<Vec<usize>> = const { RefCell::new(vec![]) };
+}
+
+/// Wraps the system allocator to count the bytes in use.
+///
+/// Peak bytes in the profile are only counted when the binary installs this with
+/// `#[global_allocator]`, otherwise they read as zero. Bytes are only counted once profiling is
+/// enabled, so other runs pay no more than a check of the flag on each allocation.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        return new;
+    }
+}
+
+fn grow(size: usize) {
+    if !is_enabled() {
+        return;
+    }
+
+    let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn shrink(size: usize) {
+    if is_enabled() {
+        CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+/// One named span in the tree, with repeated spans under the same parent merged together
+#[derive(Debug, Clone)]
+struct Node {
+    name: String,
+    parent: Option<usize>,
+    calls: usize,
+    time: Duration,
+    peak_bytes: usize,
+}
+
+/// Start recording spans. Until this is called `span` does nothing
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stop recording spans and counting the heap. Spans already open are still recorded
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    return ENABLED.load(Ordering::Relaxed);
+}
+
+/// Open a span that lasts until the returned guard is dropped.
+///
+/// Spans nest under whichever span is open on the same thread. The peak is the most heap
+/// in use above what was in use when the span opened, which also counts other threads. Spans
+/// open on different threads at the same time reset each other's peaks, so only profile one
+/// task at a time.
+pub fn span(name: &str) -> Span {
+    if !is_enabled() {
+        return Span { open: None };
+    }
+
+    let index = enter(name);
+
+    let base = CURRENT.load(Ordering::Relaxed);
+    let outer_peak = PEAK.swap(base, Ordering::Relaxed);
+
+    return Span {
+        open: Some(Open {
+            index,
+            start: Instant::now(),
+            base,
+            outer_peak,
+        }),
+    };
+}
+
+/// Guard for an open span, see `span`
+#[must_use = "the span closes as soon as this is dropped"]
+pub struct Span {
+    open: Option<Open>,
+}
+
+struct Open {
+    index: usize,
+    start: Instant,
+    base: isize,
+    outer_peak: isize,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let Some(open) = self.open.take() else {
+            return;
+        };
+
+        let time = open.start.elapsed();
+        let peak = PEAK.fetch_max(open.outer_peak, Ordering::Relaxed);
+        let peak_bytes = (peak - open.base).max(0) as usize;
+
+        STACK.with(|stack| stack.borrow_mut().pop());
+
+        let mut nodes = NODES.lock().unwrap();
+        let node = &mut nodes[open.index];
+        node.calls += 1;
+        node.time += time;
+        node.peak_bytes = node.peak_bytes.max(peak_bytes);
+    }
+}
+
+/// Find or add the node for `name` under the innermost open span, and push it
+fn enter(name: &str) -> usize {
+    return STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let parent = stack.last().copied();
+
+        let mut nodes = NODES.lock().unwrap();
+        let index = match nodes
+            .iter()
+            .position(|n| n.parent == parent && n.name == name)
+        {
+            Some(index) => index,
+            None => {
+                nodes.push(Node {
+                    name: name.to_string(),
+                    parent,
+                    calls: 0,
+                    time: Duration::ZERO,
+                    peak_bytes: 0,
+                });
+                nodes.len() - 1
+            }
+        };
+
+        stack.push(index);
+        return index;
+    });
+}
+
+/// The recorded spans as an indented tree, with each span's share of the total time
+pub fn report() -> String {
+    let nodes = NODES.lock().unwrap().clone();
+
+    let mut rows = vec![];
+    for (i, node) in nodes.iter().enumerate() {
+        if node.parent.is_none() {
+            add_rows(&nodes, i, "", "", &mut rows);
+        }
+    }
+
+    let total: Duration = nodes
+        .iter()
+        .filter(|n| n.parent.is_none())
+        .map(|n| n.time)
+        .sum();
+
+    let width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<width$} | {:>5} | {:>10} | {:>6} | {:>10}",
+        "Span", "Calls", "Time", "Share", "Peak heap"
+    )
+    .unwrap();
+    writeln!(out, "{}", "-".repeat(width + 45)).unwrap();
+
+    for (label, i) in rows {
+        let node = &nodes[i];
+        let share = if total.is_zero() {
+            0.0
+        } else {
+            100.0 * node.time.as_secs_f64() / total.as_secs_f64()
+        };
+
+        writeln!(
+            out,
+            "{:<width$} | {:>5} | {:>10} | {:>5.1}% | {:>10}",
+            label,
+            node.calls,
+            format!("{:.2?}", node.time),
+            share,
+            format_bytes(node.peak_bytes)
+        )
+        .unwrap();
+    }
+
+    return out;
+}
+
+fn add_rows(
+    nodes: &[Node],
+    i: usize,
+    prefix: &str,
+    children_prefix: &str,
+    rows: &mut Vec<(String, usize)>,
+) {
+    rows.push((format!("{}{}", prefix, nodes[i].name), i));
+
+    let children: Vec<usize> = (0..nodes.len())
+        .filter(|&c| nodes[c].parent == Some(i))
+        .collect();
+
+    for (k, &child) in children.iter().enumerate() {
+        let (branch, rest) = if k + 1 == children.len() {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+
+        add_rows(
+            nodes,
+            child,
+            &format!("{}{}", children_prefix, branch),
+            &format!("{}{}", children_prefix, rest),
+            rows,
+        );
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        return format!("{} B", bytes);
+    }
+
+    return format!("{:.1} {}", size, UNITS[unit]);
+}
+
+#[test]
+fn test_report() {
+    // Spans opened before profiling is enabled are not recorded
+    drop(span("test before enabling"));
+
+    enable();
+    {
+        let _outer = span("test outer");
+        for _ in 0..3 {
+            let _inner = span("test inner");
+        }
+    }
+
+    // Other tests in this binary must not be profiled
+    disable();
+    drop(span("test after disabling"));
+
+    let report = report();
+    assert!(!report.contains("test before enabling"));
+    assert!(!report.contains("test after disabling"));
+    assert!(report.contains("test outer"));
+    assert!(report
+        .lines()
+        .any(|l| l.starts_with("└─ test inner") && l.contains("|     3 |")));
+
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MiB");
+}
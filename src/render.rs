@@ -0,0 +1,347 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::{grid::Grid, Error, Result};
+
+/// An RGB colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colour(pub u8, pub u8, pub u8);
+
+impl Colour {
+    pub const BLACK: Colour = Colour(0, 0, 0);
+    pub const WHITE: Colour = Colour(255, 255, 255);
+    pub const GREY: Colour = Colour(128, 128, 128);
+    pub const DARK_GREY: Colour = Colour(48, 48, 48);
+    pub const RED: Colour = Colour(220, 50, 47);
+    pub const GREEN: Colour = Colour(80, 190, 80);
+    pub const BLUE: Colour = Colour(60, 120, 220);
+    pub const YELLOW: Colour = Colour(240, 200, 40);
+
+    /// The colour `t` of the way from `self` to `other`, with `t` clamped to 0..=1
+    pub fn mix(self, other: Colour, t: f64) -> Colour {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        return Colour(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        );
+    }
+
+    fn hex(self) -> String {
+        return format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2);
+    }
+}
+
+/// The file extensions [`Picture::save`] can write
+pub const EXTENSIONS: [&str; 3] = ["ppm", "png", "svg"];
+
+/// Whether [`Picture::save`] can write to `path`, judging by its extension
+pub fn supports(path: &str) -> bool {
+    return extension(path).is_some_and(|e| EXTENSIONS.contains(&e.as_str()));
+}
+
+fn extension(path: &str) -> Option<String> {
+    return Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+}
+
+/// Most pixels a picture is scaled up to along its longer side
+const TARGET_SIZE: usize = 800;
+
+/// A grid puzzle drawn as one colour per cell, with paths drawn over the cells
+pub struct Picture {
+    width: usize,
+    height: usize,
+    cells: Vec<Colour>,
+    paths: Vec<(Vec<(usize, usize)>, Colour)>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize, background: Colour) -> Self {
+        return Picture {
+            width,
+            height,
+            cells: vec![background; width * height],
+            paths: vec![],
+        };
+    }
+
+    /// A picture the size of `grid`, colouring each cell by its value
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Colour) -> Self {
+        let mut picture = Picture::new(grid.width(), grid.height(), Colour::BLACK);
+
+        for ((i, j), value) in grid.iter() {
+            picture.set(i, j, colour(value));
+        }
+
+        return picture;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<Colour> {
+        if i >= self.height || j >= self.width {
+            return None;
+        }
+
+        return Some(self.cells[i * self.width + j]);
+    }
+
+    /// Colours the cell in row `i` and column `j`. Cells outside the picture are ignored
+    pub fn set(&mut self, i: usize, j: usize, colour: Colour) {
+        if i < self.height && j < self.width {
+            self.cells[i * self.width + j] = colour;
+        }
+    }
+
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, colour: Colour) {
+        for (i, j) in cells {
+            self.set(i, j, colour);
+        }
+    }
+
+    /// Draws a line through the centres of `cells`, in order, over the cells
+    pub fn path(&mut self, cells: Vec<(usize, usize)>, colour: Colour) {
+        self.paths.push((cells, colour));
+    }
+
+    /// Writes the picture to `path` as PPM, PNG or SVG, chosen by the file's extension
+    pub fn save(&self, path: &str) -> Result<()> {
+        let io_error = |source| Error::Io {
+            path: path.to_string(),
+            source,
+        };
+
+        let contents = match extension(path).as_deref() {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png().map_err(io_error)?,
+            Some("svg") => self.to_svg().into_bytes(),
+            _ => {
+                return Err(io_error(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "unknown image format, expected a .ppm, .png or .svg file",
+                )))
+            }
+        };
+
+        return fs::write(path, contents).map_err(io_error);
+    }
+
+    /// A binary PPM (P6) image
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.raster();
+
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        ppm.extend(pixels);
+
+        return ppm;
+    }
+
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        let (width, height, pixels) = self.raster();
+
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .map_err(io::Error::other)?;
+
+        return Ok(png);
+    }
+
+    /// An SVG image with one unit per cell, scaled to the same size as the raster images
+    pub fn to_svg(&self) -> String {
+        let scale = self.scale();
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            self.width * scale,
+            self.height * scale,
+            self.width,
+            self.height
+        );
+        let _ = writeln!(svg, r#"<g shape-rendering="crispEdges">"#);
+
+        // One rectangle per run of equal cells keeps large grids small
+        for i in 0..self.height {
+            let row = &self.cells[i * self.width..(i + 1) * self.width];
+            let mut j = 0;
+
+            while j < row.len() {
+                let run = row[j..].iter().take_while(|&&c| c == row[j]).count();
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="1" fill="{}"/>"#,
+                    j,
+                    i,
+                    run,
+                    row[j].hex()
+                );
+                j += run;
+            }
+        }
+        let _ = writeln!(svg, "</g>");
+
+        for (cells, colour) in &self.paths {
+            let points: Vec<String> = cells
+                .iter()
+                .map(|(i, j)| format!("{}.5,{}.5", j, i))
+                .collect();
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="0.3" stroke-linejoin="round"/>"#,
+                points.join(" "),
+                colour.hex()
+            );
+        }
+
+        svg.push_str("</svg>\n");
+
+        return svg;
+    }
+
+    /// Pixels per cell, so that the longer side comes out close to `TARGET_SIZE`
+    fn scale(&self) -> usize {
+        return (TARGET_SIZE / self.width.max(self.height).max(1)).clamp(1, 16);
+    }
+
+    /// The picture as rows of RGB pixels, with its width and height in pixels
+    fn raster(&self) -> (usize, usize, Vec<u8>) {
+        let scale = self.scale();
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut pixels = vec![0; width * height * 3];
+
+        let mut paint = |x: usize, y: usize, colour: Colour| {
+            if x < width && y < height {
+                let k = (y * width + x) * 3;
+                pixels[k..k + 3].copy_from_slice(&[colour.0, colour.1, colour.2]);
+            }
+        };
+
+        for i in 0..self.height {
+            for j in 0..self.width {
+                let colour = self.cells[i * self.width + j];
+                for y in i * scale..(i + 1) * scale {
+                    for x in j * scale..(j + 1) * scale {
+                        paint(x, y, colour);
+                    }
+                }
+            }
+        }
+
+        // Lines are a third of a cell thick, centred on the cells they pass through
+        let thickness = (scale / 3).max(1);
+        let centre = |n: usize| (n * scale + scale / 2) as i64;
+
+        for (cells, colour) in &self.paths {
+            for step in cells.windows(2) {
+                let from = (centre(step[0].1), centre(step[0].0));
+                let to = (centre(step[1].1), centre(step[1].0));
+
+                for (x, y) in line(from, to) {
+                    for dy in 0..thickness {
+                        for dx in 0..thickness {
+                            let x = x - (thickness / 2) as i64 + dx as i64;
+                            let y = y - (thickness / 2) as i64 + dy as i64;
+                            if x >= 0 && y >= 0 {
+                                paint(x as usize, y as usize, *colour);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        return (width, height, pixels);
+    }
+}
+
+/// The points on the straight line from `from` to `to`, both included, by Bresenham's algorithm
+fn line(from: (i64, i64), to: (i64, i64)) -> Vec<(i64, i64)> {
+    let (mut x, mut y) = from;
+    let dx = (to.0 - x).abs();
+    let dy = -(to.1 - y).abs();
+    let sx = if x < to.0 { 1 } else { -1 };
+    let sy = if y < to.1 { 1 } else { -1 };
+    let mut error = dx + dy;
+    let mut points = vec![];
+
+    loop {
+        points.push((x, y));
+        if (x, y) == to {
+            break;
+        }
+
+        let e2 = 2 * error;
+        if e2 >= dy {
+            error += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            error += dx;
+            y += sy;
+        }
+    }
+
+    return points;
+}
+
+#[test]
+fn test_line() {
+    assert_eq!(line((0, 0), (3, 0)), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+    assert_eq!(line((2, 2), (0, 0)), vec![(2, 2), (1, 1), (0, 0)]);
+    assert_eq!(line((1, 1), (1, 1)), vec![(1, 1)]);
+}
+
+#[test]
+fn test_picture() {
+    let grid: Grid<char> = Grid::parse(&["#.", ".#"]).unwrap();
+    let mut picture = Picture::from_grid(&grid, |&c| match c {
+        '#' => Colour::WHITE,
+        _ => Colour::BLACK,
+    });
+    picture.highlight([(0, 1)], Colour::RED);
+    picture.path(vec![(0, 0), (1, 1)], Colour::GREEN);
+
+    assert_eq!(picture.get(0, 0), Some(Colour::WHITE));
+    assert_eq!(picture.get(0, 1), Some(Colour::RED));
+    assert_eq!(picture.get(2, 0), None);
+
+    let scale = picture.scale();
+    let ppm = picture.to_ppm();
+    let header = format!("P6\n{} {}\n255\n", 2 * scale, 2 * scale);
+    assert!(ppm.starts_with(header.as_bytes()));
+    assert_eq!(ppm.len(), header.len() + 2 * scale * 2 * scale * 3);
+
+    let png = picture.to_png().unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+
+    let svg = picture.to_svg();
+    assert!(svg.contains(r##"fill="#dc322f""##));
+    assert!(svg.contains(r#"points="0.5,0.5 1.5,1.5""#));
+}
+
+#[test]
+fn test_save_unknown_format() {
+    assert!(supports("out/picture.PNG"));
+    assert!(!supports("picture.jpg"));
+    assert!(!supports("picture"));
+
+    let picture = Picture::new(1, 1, Colour::BLACK);
+    assert!(picture.save("picture.jpg").is_err());
+}
@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+use crate::{animate::Frames, error::Result, generate::Generator, render::Picture};
+
+/// A single day's puzzle. The input is parsed once and then shared by both parts
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Writes a random input in the puzzle's format, for stress tests. Each day says what the
+    /// generator's size counts
+    fn generate(generator: &mut Generator) -> String
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    /// Solves part 1 with the example's parameters, for days where they differ from the real input
+    fn part1_demo(&self) -> Result<Answer> {
+        return self.part1();
+    }
+
+    /// Solves part 2 with the example's parameters, for days where they differ from the real input
+    fn part2_demo(&self) -> Result<Answer> {
+        return self.part2();
+    }
+
+    /// Draws the input with what the parts found in it, for days that can. See [`render`](crate::render)
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(None);
+    }
+
+    /// Steps through the day's simulation, pushing each state to `frames`. False for days
+    /// without one
+    fn animate(&self, _frames: &mut dyn Frames) -> Result<bool> {
+        return Ok(false);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    Float(f64),
+    /// The puzzle has no such part, e.g. part 2 of day 25
+    None,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Forward to the inner value so that width and alignment are respected
+            Answer::Integer(x) => x.fmt(f),
+            Answer::Float(x) => x.fmt(f),
+            Answer::None => f.pad("-"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        return Answer::Integer(value as i64);
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        return Answer::Integer(value);
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        return Answer::Integer(value as i64);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        return Answer::Integer(value as i64);
+    }
+}
+
+impl From<f64> for Answer {
+    fn from(value: f64) -> Self {
+        return Answer::Float(value);
+    }
+}
+
+/// Entry in the registry of solutions, added by each day with [`register!`]
+pub struct Registration {
+    pub year: u16,
+    pub day: usize,
+    /// Input file used when none is given on the command line
+    pub default_input: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Solution>>,
+    /// Example inputs from the puzzle text, with their known answers
+    pub demos: &'static [Demo],
+    pub generate: fn(&mut Generator) -> String,
+}
+
+/// An example input for one part, see [`Solution::part1_demo`]
+pub struct Demo {
+    pub part: u8,
+    pub input: &'static str,
+    pub expected: Answer,
+}
+
+/// Parses `input` into `S`, erasing the type so that every day can live in one registry
+pub fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>> {
+    return Ok(Box::new(S::parse(input)?));
+}
+
+inventory::collect!(Registration);
+
+/// Marks a year and day as registered. [`register!`] implements [`Registered`] for it, so
+/// registering the same day twice fails to compile with conflicting implementations
+pub struct Puzzle<const YEAR: u16, const DAY: usize>;
+
+pub trait Registered {}
+
+/// Adds a day to the registry, making it available to the CLI, the benchmarks and the tests
+macro_rules! register {
+    (
+        year: $year:literal,
+        day: $day:literal,
+        solution: $solution:ty,
+        input: $input:literal,
+        demos: $demos:expr $(,)?
+    ) => {
+        impl $crate::solution::Registered for $crate::solution::Puzzle<$year, $day> {}
+
+        inventory::submit! {
+            $crate::Registration {
+                year: $year,
+                day: $day,
+                default_input: $input,
+                parse: $crate::parse_boxed::<$solution>,
+                demos: $demos,
+                generate: <$solution as $crate::Solution>::generate,
+            }
+        }
+    };
+}
+
+pub(crate) use register;
@@ -0,0 +1,195 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use sha2::{Digest, Sha256};
+
+use crate::{answers::error_at, read_input, Error, Result};
+
+/// Puzzle inputs kept per profile and year as `<root>/<profile>/<year>/dayN.txt`, so several
+/// people's inputs and answers can live side by side.
+///
+/// Each profile and year also keeps a `checksums.toml` of the SHA-256 of every input whose answers
+/// have been verified, so a changed input can be spotted before its old answers are trusted.
+///
+/// Profiles from before years were kept apart hold their 2023 files directly in
+/// `<root>/<profile>/`, and are still read from there until a `2023` directory is made.
+pub struct InputStore {
+    dir: String,
+    profile: String,
+}
+
+/// How an input compares with the checksum recorded when its answers were verified
+#[derive(Debug, PartialEq)]
+pub enum Checksum {
+    Unrecorded,
+    Matches,
+    Changed,
+}
+
+/// The only year whose files could be kept without a year directory
+const UNDATED_YEAR: u16 = 2023;
+
+impl InputStore {
+    pub fn new(root: &str, profile: &str, year: u16) -> Self {
+        let profile_dir = format!("{}/{}", root, profile);
+        let mut dir = format!("{}/{}", profile_dir, year);
+
+        if year == UNDATED_YEAR && !Path::new(&dir).exists() && has_undated_files(&profile_dir) {
+            dir = profile_dir;
+        }
+
+        return InputStore {
+            dir,
+            profile: profile.to_string(),
+        };
+    }
+
+    pub fn profile(&self) -> &str {
+        return &self.profile;
+    }
+
+    /// Where the profile keeps the input for `day`, whether or not it exists yet
+    pub fn input_path(&self, day: usize) -> String {
+        return format!("{}/day{}.txt", self.dir, day);
+    }
+
+    /// The profile's input for `day`, if it has one
+    pub fn find_input(&self, day: usize) -> Option<String> {
+        let path = self.input_path(day);
+        return Path::new(&path).is_file().then_some(path);
+    }
+
+    pub fn answers_path(&self) -> String {
+        return format!("{}/answers.toml", self.dir);
+    }
+
+    fn checksums_path(&self) -> String {
+        return format!("{}/checksums.toml", self.dir);
+    }
+
+    pub fn check(&self, day: usize, contents: &str) -> Result<Checksum> {
+        return Ok(match self.load_checksums()?.get(&format!("day{}", day)) {
+            None => Checksum::Unrecorded,
+            Some(recorded) if *recorded == sha256(contents) => Checksum::Matches,
+            Some(_) => Checksum::Changed,
+        });
+    }
+
+    /// Remember `contents` as the input that the answers for `day` were verified against
+    pub fn record(&self, day: usize, contents: &str) -> Result<()> {
+        let mut checksums = self.load_checksums()?;
+        checksums.insert(format!("day{}", day), sha256(contents));
+
+        let path = self.checksums_path();
+        let contents = toml::to_string(&checksums).expect("Checksums always serialise");
+
+        return fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, contents))
+            .map_err(|source| Error::Io { path, source });
+    }
+
+    fn load_checksums(&self) -> Result<BTreeMap<String, String>> {
+        let path = self.checksums_path();
+        if !Path::new(&path).exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let contents = read_input(&path)?;
+
+        return toml::from_str(&contents).map_err(|e| {
+            let offset = e.span().map_or(0, |span| span.start);
+            error_at(&contents, offset, e.message())
+        });
+    }
+}
+
+/// Whether `dir` holds inputs, answers or checksums directly rather than in year directories
+fn has_undated_files(dir: &str) -> bool {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+
+    return entries.flatten().any(|entry| {
+        let name = entry.file_name().to_string_lossy().to_string();
+        return name == "answers.toml"
+            || name == "checksums.toml"
+            || (name.starts_with("day") && name.ends_with(".txt"));
+    });
+}
+
+/// Profile names become directory names, so keep them to letters, digits, `-` and `_`
+pub fn valid_profile_name(name: &str) -> bool {
+    return !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+}
+
+/// Lowercase hex SHA-256 of `contents`
+pub fn sha256(contents: &str) -> String {
+    return Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+}
+
+#[test]
+fn test_sha256() {
+    assert_eq!(
+        sha256("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_checksums() {
+    let root = std::env::temp_dir().join(format!("aoc_store_{}", std::process::id()));
+    let root = root.to_string_lossy().to_string();
+
+    let store = InputStore::new(&root, "alice", 2023);
+    assert_eq!(store.input_path(3), format!("{}/alice/2023/day3.txt", root));
+    assert_eq!(store.find_input(3), None);
+
+    assert_eq!(store.check(3, "1 2 3").unwrap(), Checksum::Unrecorded);
+    store.record(3, "1 2 3").unwrap();
+    assert_eq!(store.check(3, "1 2 3").unwrap(), Checksum::Matches);
+    assert_eq!(store.check(3, "1 2 4").unwrap(), Checksum::Changed);
+    assert_eq!(store.check(4, "1 2 3").unwrap(), Checksum::Unrecorded);
+
+    // Other profiles and years keep their own checksums
+    let other = InputStore::new(&root, "bob", 2023);
+    assert_eq!(other.check(3, "1 2 3").unwrap(), Checksum::Unrecorded);
+    let other = InputStore::new(&root, "alice", 2022);
+    assert_eq!(other.check(3, "1 2 3").unwrap(), Checksum::Unrecorded);
+
+    fs::remove_dir_all(&root).unwrap();
+
+    // Profiles without year directories keep their 2023 inputs and checksums
+    let legacy = format!("{}/carol", root);
+    fs::create_dir_all(&legacy).unwrap();
+    fs::write(format!("{}/day3.txt", legacy), "1 2 3").unwrap();
+    fs::write(
+        format!("{}/checksums.toml", legacy),
+        format!("day3 = \"{}\"\n", sha256("1 2 3")),
+    )
+    .unwrap();
+
+    let store = InputStore::new(&root, "carol", 2023);
+    assert_eq!(store.find_input(3), Some(format!("{}/day3.txt", legacy)));
+    assert_eq!(store.answers_path(), format!("{}/answers.toml", legacy));
+    assert_eq!(store.check(3, "1 2 4").unwrap(), Checksum::Changed);
+    let other = InputStore::new(&root, "carol", 2022);
+    assert_eq!(other.find_input(3), None);
+    let other = InputStore::new(&root, "bob", 2023);
+    assert_eq!(other.input_path(3), format!("{}/bob/2023/day3.txt", root));
+
+    // Once the year directory exists, it is used instead
+    fs::create_dir_all(format!("{}/2023", legacy)).unwrap();
+    let store = InputStore::new(&root, "carol", 2023);
+    assert_eq!(store.find_input(3), None);
+
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(valid_profile_name("alice_2"));
+    assert!(!valid_profile_name("../alice"));
+    assert!(!valid_profile_name(""));
+}
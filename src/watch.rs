@@ -0,0 +1,63 @@
+use std::{fs, time::SystemTime};
+
+/// Notices changes to a set of files by polling their modification time and size
+pub struct Watcher {
+    files: Vec<(String, Option<Stamp>)>,
+}
+
+/// What a file looked like when last polled. Size catches writes within the timer's resolution
+type Stamp = (SystemTime, u64);
+
+impl Watcher {
+    pub fn new(paths: &[String]) -> Self {
+        return Watcher {
+            files: paths.iter().map(|p| (p.clone(), stamp(p))).collect(),
+        };
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        return self.files.iter().map(|(path, _)| path.as_str());
+    }
+
+    /// The files that were written, created or removed since the last call
+    pub fn changed(&mut self) -> Vec<String> {
+        let mut changed = vec![];
+
+        for (path, last) in self.files.iter_mut() {
+            let now = stamp(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+
+        return changed;
+    }
+}
+
+fn stamp(path: &str) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+    return Some((metadata.modified().ok()?, metadata.len()));
+}
+
+#[test]
+fn test_watcher() {
+    let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("input.txt").to_string_lossy().to_string();
+    let missing = dir.join("missing.txt").to_string_lossy().to_string();
+
+    fs::write(&path, "1").unwrap();
+    let mut watcher = Watcher::new(&[path.clone(), missing]);
+    assert!(watcher.changed().is_empty());
+
+    fs::write(&path, "12").unwrap();
+    assert_eq!(watcher.changed(), vec![path.clone()]);
+    assert!(watcher.changed().is_empty());
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(watcher.changed(), vec![path]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
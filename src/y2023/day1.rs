@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::{
+    generate::Generator, lines, solution::register, Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day1 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day1 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            lines: lines(input),
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part_one(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part_two(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 1,
+    solution: Day1,
+    input: "data/day1_part1.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day1_part1_demo.txt",
+            expected: Answer::Integer(142),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day1_part2_demo.txt",
+            expected: Answer::Integer(281),
+        },
+    ],
+}
+
+fn part_one(day: &Day1) -> Result<i32> {
+    let mut sum = 0;
+
+    let split = &day.lines;
+
+    for (i, line) in split.iter().enumerate() {
+        let mut first: Option<char> = None;
+        let mut last: Option<char> = None;
+
+        for c in line.chars() {
+            let digit = c.to_digit(10);
+
+            if digit.is_some() {
+                if first.is_none() {
+                    first = Some(c);
+                    last = Some(c);
+                } else if last.is_some() {
+                    last = Some(c);
+                }
+            }
+        }
+
+        if first.is_none() || last.is_none() {
+            return Err(Error::parse(i, 0, "could not find two digits"));
+        }
+
+        let this_calibration = format!("{}{}", first.unwrap(), last.unwrap());
+
+        let this_calibration = this_calibration.parse::<i32>().unwrap();
+
+        sum += this_calibration;
+    }
+
+    return Ok(sum);
+    // Correct Answer: 54081
+}
+
+fn part_two(day: &Day1) -> Result<i32> {
+    let lines = &day.lines;
+
+    let mut map: HashMap<&str, char> = HashMap::new();
+    map.insert("one", '1');
+    map.insert("two", '2');
+    map.insert("three", '3');
+    map.insert("four", '4');
+    map.insert("five", '5');
+    map.insert("six", '6');
+    map.insert("seven", '7');
+    map.insert("eight", '8');
+    map.insert("nine", '9');
+    map.insert("1", '1');
+    map.insert("2", '2');
+    map.insert("3", '3');
+    map.insert("4", '4');
+    map.insert("5", '5');
+    map.insert("6", '6');
+    map.insert("7", '7');
+    map.insert("8", '8');
+    map.insert("9", '9');
+
+    let mut sum: i32 = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let mut first: Option<(usize, &str)> = None;
+        let mut last: Option<(usize, &str)> = None;
+
+        for key in map.keys() {
+            if let Some(x) = line.find(key) {
+                if first.is_none_or(|(at, _)| x < at) {
+                    first = Some((x, key));
+                }
+            }
+            if let Some(x) = line.rfind(key) {
+                if last.is_none_or(|(at, _)| x > at) {
+                    last = Some((x, key));
+                }
+            }
+        }
+
+        let (Some((_, first_key)), Some((_, last_key))) = (first, last) else {
+            return Err(Error::parse(i, 0, "could not find a digit"));
+        };
+
+        let (Some(first_digit), Some(last_digit)) = (map.get(first_key), map.get(last_key)) else {
+            return Err(Error::parse(i, 0, "could not read a digit"));
+        };
+
+        let calibration = format!("{}{}", first_digit, last_digit);
+
+        sum += match calibration.parse::<i32>() {
+            Ok(value) => value,
+            Err(_) => return Err(Error::parse(i, 0, "could not read the calibration value")),
+        };
+    }
+
+    return Ok(sum);
+    // correct answer: 54649
+}
+
+/// `size` lines of letters, digits and spelled out digits
+fn generate(generator: &mut Generator) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut lines = vec![];
+
+    for _ in 0..generator.size_or(1000) {
+        let mut line = String::new();
+
+        for _ in 0..generator.range(1..=6) {
+            match generator.range(0..3) {
+                0 => line.push_str(generator.choose::<&str>(&WORDS)),
+                1 => line.push_str(&generator.word("123456789", 1)),
+                _ => {
+                    let len = generator.range(1..=5);
+                    line.push_str(&generator.word("abcdefghijklmnopqrstuvwxyz", len));
+                }
+            }
+        }
+
+        // Every line has a digit
+        let at = generator.range(0..=line.len());
+        line.insert_str(at, &generator.word("123456789", 1));
+
+        lines.push(line);
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_day1_part1() {
+    let file_path = "data_demo/day1_part1_demo.txt";
+    let sum = part_one(&crate::load(file_path)).unwrap();
+    assert_eq!(sum, 142);
+}
+
+#[test]
+fn test_day1_part2() {
+    let file_path = "data_demo/day1_part2_demo.txt";
+    let sum = part_two(&crate::load(file_path)).unwrap();
+    assert_eq!(sum, 281);
+}
+
+#[test]
+fn test_day1_part2_word_at_start() {
+    let day = Day1::parse("eightwree").unwrap();
+    assert_eq!(part_two(&day).unwrap(), 88);
+
+    let day = Day1::parse("nine").unwrap();
+    assert_eq!(part_two(&day).unwrap(), 99);
+}
@@ -0,0 +1,491 @@
+use log::trace;
+
+use crate::{
+    generate::Generator,
+    geometry::Direction,
+    grid::Grid,
+    lines,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day10 {
+    grid: Grid<Pipe>,
+    start: (usize, usize),
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self> {
+        return parse_input(&lines(input));
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)?));
+    }
+}
+
+register! {
+    year: 2023,
+    day: 10,
+    solution: Day10,
+    input: "data/day10.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day10_demo.txt",
+            expected: Answer::Integer(8),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day10_demo2.txt",
+            expected: Answer::Integer(10),
+        },
+    ],
+}
+
+fn part1(day: &Day10) -> Result<i32> {
+    let grid = &day.grid;
+    let (start_i, start_j) = day.start;
+
+    let mut running = true;
+
+    let mut last_direction = find_starting_direction(&grid, start_i, start_j)?;
+    let mut i = start_i;
+    let mut j = start_j;
+
+    let mut moves = 0;
+
+    while running {
+        moves += 1;
+
+        let (new_i, new_j) = step(last_direction, i, j);
+
+        let new_pipe = pipe_at(grid, new_i, new_j)?;
+
+        trace!("{:?}", new_pipe);
+
+        if new_pipe == Pipe::Start {
+            running = false;
+            break;
+        }
+
+        let new_direction = match new_pipe.next_direction(last_direction) {
+            Some(x) => x,
+            None => return Err(broken_loop(new_i, new_j)),
+        };
+
+        last_direction = new_direction;
+
+        i = new_i;
+        j = new_j;
+    }
+
+    return Ok(moves / 2);
+}
+
+fn part2(day: &Day10) -> Result<i32> {
+    let points_on_line = find_loop(day)?;
+
+    trace!("Points on line");
+    trace!("{:?}", points_on_line);
+
+    return Ok(enclosed(&day.grid, &points_on_line).len() as i32);
+}
+
+/// The tiles of the loop in the order they are walked, starting from `S`
+fn find_loop(day: &Day10) -> Result<Vec<(i32, i32)>> {
+    let grid = &day.grid;
+    let (start_i, start_j) = day.start;
+
+    let mut running = true;
+
+    let mut last_direction = find_starting_direction(&grid, start_i, start_j)?;
+    let mut i = start_i;
+    let mut j = start_j;
+
+    let mut points_on_line = vec![];
+    while running {
+        points_on_line.push((i as i32, j as i32));
+
+        let (new_i, new_j) = step(last_direction, i, j);
+
+        let new_pipe = pipe_at(grid, new_i, new_j)?;
+
+        trace!("{:?}", new_pipe);
+
+        if new_pipe == Pipe::Start {
+            running = false;
+            break;
+        }
+
+        let new_direction = match new_pipe.next_direction(last_direction) {
+            Some(x) => x,
+            None => return Err(broken_loop(new_i, new_j)),
+        };
+
+        last_direction = new_direction;
+
+        i = new_i;
+        j = new_j;
+    }
+
+    return Ok(points_on_line);
+}
+
+/// The tiles inside the loop that are not part of it
+fn enclosed(grid: &Grid<Pipe>, points_on_line: &Vec<(i32, i32)>) -> Vec<(usize, usize)> {
+    let mut points_in_polygon = vec![];
+
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            if points_on_line.contains(&(i as i32, j as i32)) {
+                // Point on line
+                continue;
+            }
+
+            if in_polygon((i as i32, j as i32), points_on_line) {
+                points_in_polygon.push((i, j));
+            }
+        }
+    }
+
+    return points_in_polygon;
+}
+
+/// The pipes in grey, the loop in red and the tiles it encloses in green
+fn render(day: &Day10) -> Result<Picture> {
+    let points_on_line = find_loop(day)?;
+
+    let mut picture = Picture::from_grid(&day.grid, |pipe| match pipe {
+        Pipe::Ground => Colour::BLACK,
+        _ => Colour::DARK_GREY,
+    });
+    picture.highlight(enclosed(&day.grid, &points_on_line), Colour::GREEN);
+
+    let mut path: Vec<(usize, usize)> = points_on_line
+        .iter()
+        .map(|&(i, j)| (i as usize, j as usize))
+        .collect();
+    path.push(day.start);
+    picture.path(path, Colour::RED);
+
+    return Ok(picture);
+}
+
+fn in_polygon(point: (i32, i32), polygon: &Vec<(i32, i32)>) -> bool {
+    let mut inside = false;
+    let n = polygon.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+
+        let vi = polygon[i];
+        let vj = polygon[j];
+
+        // Check if the ray intersects with the polygon edge
+        if ((vi.0 > point.0) != (vj.0 > point.0))
+            && (point.1 < (vj.1 - vi.1) * (point.0 - vi.0) / (vj.0 - vi.0) + vi.1)
+        {
+            inside = !inside;
+        }
+    }
+
+    return inside;
+}
+
+fn pipe_at(grid: &Grid<Pipe>, i: usize, j: usize) -> Result<Pipe> {
+    return match grid.get(i, j) {
+        Some(pipe) => Ok(pipe.clone()),
+        None => Err(broken_loop(i, j)),
+    };
+}
+
+fn broken_loop(i: usize, j: usize) -> Error {
+    return Error::unsolvable(format!(
+        "the loop is broken at line {}, column {}",
+        i + 1,
+        j + 1
+    ));
+}
+
+fn parse_input(lines: &Vec<String>) -> Result<Day10> {
+    let grid = Grid::parse(lines)?;
+
+    return match grid.position(|pipe| *pipe == Pipe::Start) {
+        Some(start) => Ok(Day10 { grid, start }),
+        None => Err(Error::parse(0, 0, "could not find the start `S`")),
+    };
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Pipe {
+    Vertical,      // |
+    Horizontal,    // -
+    NorthEastPipe, // L
+    NorthWestPipe, // J
+    SouthWestPipe, // 7
+    SouthEastPipe, // F
+    Ground,        // .
+    Start,         // S
+}
+
+impl Pipe {
+    /// The direction out of this pipe, or `None` if it does not connect to the previous pipe
+    fn next_direction(self, previous_direction: Direction) -> Option<Direction> {
+        if previous_direction == Direction::North {
+            if self == Self::Vertical {
+                return Some(Direction::North);
+            } else if self == Self::SouthEastPipe {
+                return Some(Direction::East);
+            } else if self == Self::SouthWestPipe {
+                return Some(Direction::West);
+            } else {
+                return None;
+            }
+        } else if previous_direction == Direction::East {
+            if self == Self::Horizontal {
+                return Some(Direction::East);
+            } else if self == Self::NorthWestPipe {
+                return Some(Direction::North);
+            } else if self == Self::SouthWestPipe {
+                return Some(Direction::South);
+            } else {
+                return None;
+            }
+        } else if previous_direction == Direction::South {
+            if self == Self::Vertical {
+                return Some(Direction::South);
+            } else if self == Self::NorthEastPipe {
+                return Some(Direction::East);
+            } else if self == Self::NorthWestPipe {
+                return Some(Direction::West);
+            } else {
+                return None;
+            }
+        } else if previous_direction == Direction::West {
+            if self == Self::Horizontal {
+                return Some(Direction::West);
+            } else if self == Self::NorthEastPipe {
+                return Some(Direction::North);
+            } else if self == Self::SouthEastPipe {
+                return Some(Direction::South);
+            } else {
+                return None;
+            }
+        }
+
+        return None;
+    }
+}
+fn find_starting_direction(grid: &Grid<Pipe>, start_i: usize, start_j: usize) -> Result<Direction> {
+    // North
+
+    let height = grid.height();
+    let width = grid.width();
+
+    if start_i > 0 {
+        let (ni, nj) = step(Direction::North, start_i, start_j);
+
+        match pipe_at(grid, ni, nj)? {
+            Pipe::Vertical | Pipe::SouthEastPipe | Pipe::SouthWestPipe => {
+                return Ok(Direction::North)
+            }
+            _ => {}
+        };
+    }
+
+    // East
+    if start_j + 1 < width {
+        let (ei, ej) = step(Direction::East, start_i, start_j);
+
+        match pipe_at(grid, ei, ej)? {
+            Pipe::Horizontal | Pipe::NorthWestPipe | Pipe::SouthWestPipe => {
+                return Ok(Direction::East)
+            }
+            _ => {}
+        };
+    }
+
+    // South
+    if start_i + 1 < height {
+        let (si, sj) = step(Direction::South, start_i, start_j);
+
+        match pipe_at(grid, si, sj)? {
+            Pipe::Vertical | Pipe::NorthWestPipe | Pipe::NorthEastPipe => {
+                return Ok(Direction::South)
+            }
+            _ => {}
+        };
+    }
+
+    // West
+    if start_j > 0 {
+        let (wi, wj) = step(Direction::West, start_i, start_j);
+
+        match pipe_at(grid, wi, wj)? {
+            Pipe::Horizontal | Pipe::NorthEastPipe | Pipe::SouthEastPipe => {
+                return Ok(Direction::West)
+            }
+            _ => {}
+        };
+    }
+
+    return Err(Error::unsolvable("no pipe connects to the start"));
+}
+
+// Stepping off the top or left wraps to usize::MAX, which is then off the grid
+fn step(direction: Direction, i: usize, j: usize) -> (usize, usize) {
+    let delta = direction.delta();
+
+    return (
+        i.wrapping_add_signed(delta.y as isize),
+        j.wrapping_add_signed(delta.x as isize),
+    );
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = char;
+
+    fn try_from(c: char) -> std::result::Result<Self, char> {
+        return match c {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NorthEastPipe),
+            'J' => Ok(Self::NorthWestPipe),
+            '7' => Ok(Self::SouthWestPipe),
+            'F' => Ok(Self::SouthEastPipe),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err(c),
+        };
+    }
+}
+
+/// A `size` by `size` field of pipes with one long loop through it
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(140).max(3);
+    let path = generator.loop_path(size, size);
+
+    let mut grid = Grid::new(size, size, '.');
+    for i in 0..size {
+        for j in 0..size {
+            grid[(i, j)] = *generator.choose(&['|', '-', 'L', 'J', '7', 'F', '.', '.']);
+        }
+    }
+
+    for (k, &(i, j)) in path.iter().enumerate() {
+        let before = path[(k + path.len() - 1) % path.len()];
+        let after = path[(k + 1) % path.len()];
+
+        let mut ends = [side_of((i, j), before), side_of((i, j), after)];
+        ends.sort();
+
+        grid[(i, j)] = match ends {
+            ['E', 'N'] => 'L',
+            ['N', 'W'] => 'J',
+            ['S', 'W'] => '7',
+            ['E', 'S'] => 'F',
+            ['N', 'S'] => '|',
+            _ => '-',
+        };
+    }
+
+    // The start only connects to its two neighbours on the loop
+    let start = path[0];
+    let on_loop = [path[1], path[path.len() - 1]];
+    let neighbours: Vec<(usize, usize)> = grid.neighbours_4(start.0, start.1).collect();
+    for neighbour in neighbours {
+        if !on_loop.contains(&neighbour) {
+            grid[neighbour] = '.';
+        }
+    }
+    grid[start] = 'S';
+
+    return grid.to_string();
+}
+
+/// Which side of `from` its neighbour `to` is on, as `N`, `E`, `S` or `W`
+fn side_of(from: (usize, usize), to: (usize, usize)) -> char {
+    return match (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64) {
+        (-1, _) => 'N',
+        (1, _) => 'S',
+        (_, 1) => 'E',
+        _ => 'W',
+    };
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day10_demo.txt";
+    let count = part1(&crate::load(path)).unwrap();
+    assert_eq!(count, 8);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day10_demo2.txt";
+    let count = part2(&crate::load(path)).unwrap();
+    assert_eq!(count, 10);
+}
+
+/// The tiles the loop encloses, found by drawing the loop at double scale, so that squeezing
+/// between pipes leaves a gap, then flooding everything outside it
+#[cfg(test)]
+fn brute_force_enclosed(grid: &Grid<Pipe>, points_on_line: &[(i32, i32)]) -> usize {
+    // Tile (i, j) is at (2i + 1, 2j + 1), leaving a border the flood can go all the way round
+    let height = 2 * grid.height() + 1;
+    let width = 2 * grid.width() + 1;
+    let mut wall = Grid::new(width, height, false);
+
+    for (k, &(i, j)) in points_on_line.iter().enumerate() {
+        let (next_i, next_j) = points_on_line[(k + 1) % points_on_line.len()];
+        wall[((2 * i + 1) as usize, (2 * j + 1) as usize)] = true;
+        wall[((i + next_i + 1) as usize, (j + next_j + 1) as usize)] = true;
+    }
+
+    let mut outside = Grid::new(width, height, false);
+    let mut stack = vec![(0, 0)];
+    while let Some(p) = stack.pop() {
+        if outside[p] || wall[p] {
+            continue;
+        }
+
+        outside[p] = true;
+        stack.extend(outside.neighbours_4(p.0, p.1));
+    }
+
+    return grid
+        .iter()
+        .filter(|&((i, j), _)| !wall[(2 * i + 1, 2 * j + 1)] && !outside[(2 * i + 1, 2 * j + 1)])
+        .count();
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, size in 3..=12usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let day = Day10::parse(&generate(&mut generator)).unwrap();
+
+        let points_on_line = find_loop(&day).unwrap();
+        proptest::prop_assert_eq!(
+            part2(&day).unwrap() as usize,
+            brute_force_enclosed(&day.grid, &points_on_line)
+        );
+    }
+}
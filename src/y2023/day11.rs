@@ -0,0 +1,416 @@
+use std::{
+    cmp::{max, min},
+    fmt::Display,
+};
+
+use log::debug;
+
+use crate::{
+    generate::Generator, grid::Grid, lines, solution::register, Answer, Demo, Result, Solution,
+};
+
+pub struct Day11 {
+    universe: Grid<Universe>,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            universe: Grid::parse(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self, 1_000_000)?.into());
+    }
+
+    fn part2_demo(&self) -> Result<Answer> {
+        return Ok(part2(self, 10)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 11,
+    solution: Day11,
+    input: "data/day11.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day11_demo.txt",
+            expected: Answer::Integer(374),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day11_demo.txt",
+            expected: Answer::Integer(1030),
+        },
+    ],
+}
+
+fn part1(day: &Day11) -> Result<i64> {
+    let universe = &day.universe;
+
+    let universe = expand_universe(universe)?;
+
+    let galaxy_indecies = find_indecies(&universe);
+
+    let distances = find_shortest_distances(&galaxy_indecies);
+
+    let sum = distances.iter().sum();
+
+    return Ok(sum);
+}
+
+fn part2(day: &Day11, scale_factor: i64) -> Result<i64> {
+    let universe = &day.universe;
+
+    let galaxy_indecies = find_indecies(&universe);
+
+    let distances = find_shortest_distances_alt(&universe, &galaxy_indecies, scale_factor);
+
+    let sum = distances.iter().sum();
+
+    return Ok(sum);
+}
+
+fn find_shortest_distances_alt(
+    universe: &Grid<Universe>,
+    index: &Vec<(i64, i64)>,
+    scale_factor: i64,
+) -> Vec<i64> {
+    let mut out = vec![];
+    let len = index.len();
+
+    for (j, galaxy) in index.iter().enumerate() {
+        for i in j..len {
+            let comp_galaxy = &index[i];
+
+            if galaxy == comp_galaxy {
+                continue;
+            }
+
+            let row_min = min(galaxy.0, comp_galaxy.0);
+            let row_max = max(galaxy.0, comp_galaxy.0);
+
+            let col_min = min(galaxy.1, comp_galaxy.1);
+            let col_max = max(galaxy.1, comp_galaxy.1);
+
+            let empty_rows = count_blank_rows(universe, row_min as usize, row_max as usize);
+            let empty_columns = count_blank_columns(universe, col_min as usize, col_max as usize);
+
+            let new_distance = (galaxy.0 - comp_galaxy.0).abs()
+                + (galaxy.1 - comp_galaxy.1).abs()
+                + (scale_factor - 1) * empty_rows
+                + (scale_factor - 1) * empty_columns;
+
+            out.push(new_distance);
+        }
+    }
+
+    return out;
+}
+
+fn find_shortest_distances(index: &Vec<(i64, i64)>) -> Vec<i64> {
+    let mut out = vec![];
+    let len = index.len();
+
+    for (j, galaxy) in index.iter().enumerate() {
+        for i in j..len {
+            let comp_galaxy = &index[i];
+
+            if galaxy == comp_galaxy {
+                continue;
+            }
+
+            let new_distance = (galaxy.0 - comp_galaxy.0).abs() + (galaxy.1 - comp_galaxy.1).abs();
+
+            out.push(new_distance);
+        }
+    }
+
+    return out;
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Universe {
+    Void,
+    Galaxy,
+}
+
+impl TryFrom<char> for Universe {
+    type Error = char;
+
+    fn try_from(c: char) -> std::result::Result<Self, char> {
+        return match c {
+            '.' => Ok(Self::Void),
+            '#' => Ok(Self::Galaxy),
+            _ => Err(c),
+        };
+    }
+}
+
+impl Display for Universe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Galaxy => write!(f, "#"),
+            Self::Void => write!(f, "."),
+        }
+    }
+}
+
+fn find_indecies(universe: &Grid<Universe>) -> Vec<(i64, i64)> {
+    let mut output = vec![];
+
+    for i in 0..universe.height() {
+        for j in 0..universe.width() {
+            if universe[(i, j)] == Universe::Galaxy {
+                output.push((i as i64, j as i64));
+            }
+        }
+    }
+
+    return output;
+}
+
+fn expand_universe(universe: &Grid<Universe>) -> Result<Grid<Universe>> {
+    let rows_to_add = find_empty_rows(universe);
+    let columns_to_add = find_empty_columns(universe);
+
+    let mut new = vec![];
+
+    for (i, row) in universe.rows().enumerate() {
+        let mut new_row = vec![];
+
+        for (j, el) in row.iter().enumerate() {
+            new_row.push(*el);
+
+            if columns_to_add.contains(&j) {
+                new_row.push(Universe::Void);
+            }
+        }
+
+        if rows_to_add.contains(&i) {
+            new.push(new_row.clone());
+        }
+
+        new.push(new_row);
+    }
+
+    return Grid::from_rows(new);
+}
+
+fn count_blank_rows(universe: &Grid<Universe>, start: usize, end: usize) -> i64 {
+    let mut sum = 0;
+    for i in start..=end {
+        if is_every_element_void(universe.row(i)) {
+            sum += 1;
+        }
+    }
+
+    return sum;
+}
+
+fn count_blank_columns(universe: &Grid<Universe>, start: usize, end: usize) -> i64 {
+    let mut sum = 0;
+
+    let len = universe.height();
+
+    for j in start..=end {
+        let mut tmp = true;
+        for i in 0..len {
+            if universe[(i, j)] == Universe::Galaxy {
+                tmp = false;
+                break;
+            }
+        }
+
+        if tmp {
+            sum += 1;
+        }
+    }
+
+    return sum;
+}
+
+fn display_universe(universe: &Grid<Universe>) {
+    debug!("{}", universe);
+}
+
+fn find_empty_rows(universe: &Grid<Universe>) -> Vec<usize> {
+    let mut out = vec![];
+    for (i, row) in universe.rows().enumerate() {
+        if is_every_element_void(row) {
+            out.push(i);
+        }
+    }
+
+    return out;
+}
+
+fn find_empty_columns(universe: &Grid<Universe>) -> Vec<usize> {
+    let mut out = vec![];
+
+    let height = universe.height();
+    let width = universe.width();
+
+    for j in 0..width {
+        let mut tmp = true;
+        for i in 0..height {
+            if universe[(i, j)] == Universe::Galaxy {
+                tmp = false;
+                break;
+            }
+        }
+
+        if tmp {
+            out.push(j);
+        }
+    }
+
+    return out;
+}
+
+fn is_every_element_void(line: &[Universe]) -> bool {
+    for c in line {
+        if *c == Universe::Galaxy {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+/// A `size` by `size` image with a few galaxies, leaving some rows and columns empty
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(140).max(2);
+
+    let empty_rows: Vec<bool> = (0..size).map(|_| generator.chance(0.05)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| generator.chance(0.05)).collect();
+
+    let mut image = Grid::new(size, size, '.');
+    for i in 0..size {
+        for j in 0..size {
+            if !empty_rows[i] && !empty_columns[j] && generator.chance(0.02) {
+                image[(i, j)] = '#';
+            }
+        }
+    }
+
+    // There is always a pair of galaxies to measure between
+    image[(0, 0)] = '#';
+    image[(size - 1, size - 1)] = '#';
+
+    return image.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day11_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 374);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day11_demo.txt";
+    let day = crate::load(path);
+    let sum = part2(&day, 10).unwrap();
+    assert_eq!(sum, 1030);
+    let sum = part2(&day, 100).unwrap();
+    assert_eq!(sum, 8410);
+}
+
+/// The sum of distances between galaxies found by copying each empty row and column out to
+/// `scale_factor` copies, then walking the expanded image
+#[cfg(test)]
+fn brute_force_distances(image: &[Vec<bool>], scale_factor: usize) -> usize {
+    let width = image[0].len();
+    let column_empty: Vec<bool> = (0..width)
+        .map(|j| image.iter().all(|row| !row[j]))
+        .collect();
+
+    let mut expanded = vec![];
+    for row in image {
+        let mut new_row = vec![];
+        for (j, &galaxy) in row.iter().enumerate() {
+            let copies = if column_empty[j] { scale_factor } else { 1 };
+            new_row.extend(std::iter::repeat(galaxy).take(copies));
+        }
+
+        let copies = if row.iter().any(|&galaxy| galaxy) {
+            1
+        } else {
+            scale_factor
+        };
+        expanded.extend(std::iter::repeat(new_row).take(copies));
+    }
+
+    let height = expanded.len();
+    let width = expanded[0].len();
+    let galaxies: Vec<(usize, usize)> = (0..height)
+        .flat_map(|i| (0..width).map(move |j| (i, j)))
+        .filter(|&(i, j)| expanded[i][j])
+        .collect();
+
+    let mut sum = 0;
+    for (n, &from) in galaxies.iter().enumerate() {
+        let mut distance = vec![vec![usize::MAX; width]; height];
+        let mut queue = std::collections::VecDeque::from([from]);
+        distance[from.0][from.1] = 0;
+
+        while let Some((i, j)) = queue.pop_front() {
+            let next = [
+                (i.wrapping_sub(1), j),
+                (i + 1, j),
+                (i, j.wrapping_sub(1)),
+                (i, j + 1),
+            ];
+            for (ni, nj) in next {
+                if ni < height && nj < width && distance[ni][nj] == usize::MAX {
+                    distance[ni][nj] = distance[i][j] + 1;
+                    queue.push_back((ni, nj));
+                }
+            }
+        }
+
+        for &(i, j) in &galaxies[n + 1..] {
+            sum += distance[i][j];
+        }
+    }
+
+    return sum;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_part2_matches_brute_force(
+        image in proptest::collection::vec(
+            proptest::collection::vec(proptest::bool::weighted(0.15), 6),
+            1..=6,
+        ),
+        scale_factor in 1..=5usize,
+    ) {
+        let input: Vec<String> = image
+            .iter()
+            .map(|row| row.iter().map(|&galaxy| if galaxy { '#' } else { '.' }).collect())
+            .collect();
+        let day = Day11 { universe: Grid::parse(&input).unwrap() };
+
+        let sum = part2(&day, scale_factor as i64).unwrap();
+        proptest::prop_assert_eq!(sum as usize, brute_force_distances(&image, scale_factor));
+
+        if scale_factor == 2 {
+            proptest::prop_assert_eq!(part1(&day).unwrap(), sum);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use crate::{
+    generate::Generator,
+    parse::{cells, integer, pair, parse_lines, separated, spaces, tag, terminated},
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day12 {
+    rows: Vec<(Vec<char>, Vec<usize>)>,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self> {
+        let row = pair(
+            terminated(cells(|c| "#.?".contains(c).then_some(c)), spaces()),
+            separated(integer(), tag(",")),
+        );
+
+        let rows = parse_lines(input, row)?;
+
+        return Ok(Self { rows });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 12,
+    solution: Day12,
+    input: "data/day12.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day12_demo.txt",
+            expected: Answer::Integer(21),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day12_demo.txt",
+            expected: Answer::Integer(525152),
+        },
+    ],
+}
+
+fn part1(day: &Day12) -> Result<usize> {
+    let mut sum = 0;
+
+    for (c, nums) in &day.rows {
+        let mut cache = HashMap::new();
+
+        let possible_ways = possible_ways(&mut cache, c, None, nums);
+
+        sum += possible_ways;
+    }
+
+    return Ok(sum);
+}
+
+fn part2(day: &Day12) -> Result<usize> {
+    let mut sum = 0;
+
+    let multiplier = 5;
+
+    for (c, nums) in &day.rows {
+        let mut new_c = vec![];
+        for _ in 0..5 {
+            new_c.push(c.clone());
+            new_c.push(vec!['?']);
+        }
+
+        new_c.pop();
+        let new_c = new_c.concat();
+
+        let nums: Vec<usize> = nums
+            .iter()
+            .cloned()
+            .cycle()
+            .take(nums.len() * multiplier)
+            .collect();
+
+        let mut cache = HashMap::new();
+
+        let possible_ways = possible_ways(&mut cache, &new_c, None, &nums);
+
+        sum += possible_ways;
+    }
+
+    return Ok(sum);
+}
+
+fn possible_ways(
+    cache: &mut HashMap<(usize, usize, usize), usize>,
+    s: &[char],
+    within: Option<usize>,
+    remaining: &[usize],
+) -> usize {
+    if s.is_empty() {
+        return match (within, remaining.len()) {
+            (None, 0) => 1,
+            (Some(x), 1) if x == remaining[0] => 1,
+            _ => 0,
+        };
+    }
+    if within.is_some() && remaining.is_empty() {
+        return 0;
+    }
+
+    let key = (s.len(), within.unwrap_or(0), remaining.len());
+    if let Some(&x) = cache.get(&key) {
+        return x;
+    }
+
+    let ways = match (s[0], within) {
+        ('.', Some(x)) if x != remaining[0] => 0,
+        ('.', Some(_)) => possible_ways(cache, &s[1..], None, &remaining[1..]),
+        ('.', None) => possible_ways(cache, &s[1..], None, remaining),
+        ('#', Some(_)) => possible_ways(cache, &s[1..], within.map(|x| x + 1), remaining),
+        ('#', None) => possible_ways(cache, &s[1..], Some(1), remaining),
+        ('?', Some(x)) => {
+            let mut ans = possible_ways(cache, &s[1..], within.map(|x| x + 1), remaining);
+            if x == remaining[0] {
+                ans += possible_ways(cache, &s[1..], None, &remaining[1..])
+            }
+            ans
+        }
+        ('?', None) => {
+            possible_ways(cache, &s[1..], Some(1), remaining)
+                + possible_ways(cache, &s[1..], None, remaining)
+        }
+        _ => unreachable!(),
+    };
+    cache.insert(key, ways);
+
+    return ways;
+}
+
+/// `size` rows of springs, each with at least one arrangement
+fn generate(generator: &mut Generator) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..generator.size_or(1000) {
+        let len = generator.range(5..=20);
+        let mut springs: Vec<char> = generator.word("#.", len).chars().collect();
+        let at = generator.range(0..len);
+        springs[at] = '#';
+
+        let groups: Vec<String> = springs
+            .split(|&c| c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+
+        // Hide about half of the springs, so the row has its real layout among its arrangements
+        let row: String = springs
+            .iter()
+            .map(|&c| if generator.chance(0.5) { '?' } else { c })
+            .collect();
+
+        lines.push(format!("{} {}", row, groups.join(",")));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day12_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 21);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day12_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 525152);
+}
+
+/// Counts the arrangements of `springs` by trying every assignment of its `?`s
+#[cfg(test)]
+fn brute_force_ways(springs: &[char], groups: &[usize]) -> usize {
+    let unknown: Vec<usize> = (0..springs.len()).filter(|&i| springs[i] == '?').collect();
+
+    let mut ways = 0;
+    for assignment in 0..1 << unknown.len() {
+        let mut row = springs.to_vec();
+        for (bit, &i) in unknown.iter().enumerate() {
+            row[i] = if assignment & (1 << bit) != 0 {
+                '#'
+            } else {
+                '.'
+            };
+        }
+
+        let found: Vec<usize> = row
+            .split(|&c| c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len())
+            .collect();
+
+        if found == groups {
+            ways += 1;
+        }
+    }
+
+    return ways;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_possible_ways_matches_brute_force(
+        springs in proptest::collection::vec(proptest::bool::ANY, 1..=14),
+        hidden in proptest::collection::vec(proptest::bool::weighted(0.6), 14),
+        extra in proptest::collection::vec(1..=4usize, 0..=1),
+    ) {
+        // Groups taken from a real layout, sometimes with one more that may not fit
+        let mut groups: Vec<usize> = springs
+            .split(|&broken| !broken)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len())
+            .collect();
+        groups.extend(extra);
+
+        let row: Vec<char> = springs
+            .iter()
+            .zip(&hidden)
+            .map(|(&broken, &hidden)| match (hidden, broken) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+
+        let ways = possible_ways(&mut HashMap::new(), &row, None, &groups);
+        proptest::prop_assert_eq!(ways, brute_force_ways(&row, &groups));
+    }
+}
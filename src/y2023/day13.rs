@@ -0,0 +1,363 @@
+use std::fmt::Display;
+
+use log::{debug, trace};
+
+use crate::{
+    generate::Generator, grid::Grid, input::blocks, parse, solution::register, Answer, Demo, Error,
+    Result, Solution,
+};
+
+pub struct Day13 {
+    blocks: Vec<Grid<Ground>>,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            blocks: parse_blocks(input)?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 13,
+    solution: Day13,
+    input: "data/day13.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day13_demo.txt",
+            expected: Answer::Integer(405),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day13_demo.txt",
+            expected: Answer::Integer(400),
+        },
+    ],
+}
+
+fn part1(day: &Day13) -> Result<usize> {
+    let parsed_lines = &day.blocks;
+
+    let mut sum = 0;
+
+    for block in parsed_lines {
+        sum += handle_one_block(&block, None);
+    }
+
+    return Ok(sum);
+}
+
+fn part2(day: &Day13) -> Result<usize> {
+    let parsed_lines = &day.blocks;
+
+    let mut sum = 0;
+
+    for (b, block) in parsed_lines.iter().enumerate() {
+        sum += part2_handle_one_block(b, &block)?;
+    }
+
+    return Ok(sum);
+}
+
+fn part2_handle_one_block(b: usize, block: &Grid<Ground>) -> Result<usize> {
+    let old = handle_one_block(block, None);
+
+    trace!("The block about to be processed");
+    trace!("{}", block);
+
+    for i in 0..block.height() {
+        for j in 0..block.width() {
+            let mut new = block.clone();
+            new[(i, j)] = block[(i, j)].flip();
+
+            trace!("New Being Looked for ");
+            trace!("{}", new);
+            let proposed = handle_one_block(&new, Some(old));
+
+            if proposed > 0 && proposed != old {
+                debug!("Smudge detected at {} {}", i, j);
+                debug!("Here is the symmetric smudge fixed");
+                debug!("{}", new);
+                return Ok(proposed);
+            }
+        }
+    }
+
+    return Err(Error::unsolvable(format!(
+        "block {} has no smudge that gives a new line of reflection",
+        b + 1
+    )));
+}
+
+fn handle_one_block(block: &Grid<Ground>, previous: Option<usize>) -> usize {
+    // Horizontal symmetry
+
+    let len = block.height();
+
+    let mut sum = 0;
+
+    // trace!("About to process this block");
+    // trace!("{}", block);
+
+    for i in 0..len - 1 {
+        let lower_reflecting_line = i;
+        let upper_reflecting_line = i + 1;
+
+        let num_on_left = lower_reflecting_line;
+        let num_on_right = len - upper_reflecting_line - 1;
+
+        let padding = find_padding(num_on_right, num_on_left);
+
+        let is_mirror = is_horizontally_symmetric(
+            block,
+            lower_reflecting_line - padding,
+            upper_reflecting_line + padding,
+        );
+
+        if is_mirror {
+            if previous.is_none() {
+                trace!("It's a mirror");
+                trace!("Lower reflecting line is {}", lower_reflecting_line);
+                sum += (lower_reflecting_line + 1) * 100;
+                break;
+            }
+
+            if previous.unwrap() != (lower_reflecting_line + 1) * 100 {
+                trace!("It's a mirror2");
+                trace!("Lower reflecting line is {}", lower_reflecting_line);
+                sum += (lower_reflecting_line + 1) * 100;
+                break;
+            }
+        }
+    }
+
+    if sum > 0 {
+        trace!("Sum is {}", sum);
+        if previous.is_none() {
+            trace!("Returning sum of {}", sum);
+            return sum;
+        }
+
+        trace!("previous {}", previous.unwrap());
+        if previous.unwrap() != sum {
+            trace!("Just unwrapped previous and it was different to sum");
+            return sum;
+        }
+    }
+
+    sum = 0;
+    let grid = block.transpose();
+
+    let len = grid.height();
+
+    for i in 0..len - 1 {
+        let lower_reflecting_line = i;
+        let upper_reflecting_line = i + 1;
+
+        let num_on_left = lower_reflecting_line;
+        let num_on_right = len - upper_reflecting_line - 1;
+
+        let padding = find_padding(num_on_right, num_on_left);
+
+        // The columns of the block are the rows of its transpose
+        let is_mirror = is_horizontally_symmetric(
+            &grid,
+            lower_reflecting_line - padding,
+            upper_reflecting_line + padding,
+        );
+
+        if is_mirror {
+            if previous.is_none() {
+                trace!(
+                    "Found vertical symmetry between cols {} and {}",
+                    lower_reflecting_line,
+                    upper_reflecting_line
+                );
+                sum += lower_reflecting_line + 1;
+                break;
+            }
+
+            if previous.unwrap() != lower_reflecting_line + 1 {
+                trace!(
+                    "2 Found vertical symmetry between cols {} and {}",
+                    lower_reflecting_line,
+                    upper_reflecting_line
+                );
+                sum += lower_reflecting_line + 1;
+                break;
+            }
+        }
+    }
+
+    return sum;
+}
+
+fn find_padding(a: usize, b: usize) -> usize {
+    let min = a.min(b);
+
+    // if min % 2 != 0 {
+    //     return min - 1;
+    // }
+
+    return min;
+}
+
+/// Whether rows `first..=last` of the block read the same from either end
+fn is_horizontally_symmetric(block: &Grid<Ground>, first: usize, last: usize) -> bool {
+    let rows = last - first + 1;
+
+    trace!(
+        "Looking for horizontal symmetry between rows {} and {}",
+        first,
+        last
+    );
+
+    for row in 0..rows / 2 {
+        if block.row(first + row) != block.row(last - row) {
+            return false;
+        }
+    }
+    trace!("Found horizontal symmetry");
+    return true;
+}
+
+fn parse_blocks(input: &str) -> Result<Vec<Grid<Ground>>> {
+    let mut out = vec![];
+
+    for block in blocks(input) {
+        out.push(parse::grid(block.first_line, &block.lines, |c| {
+            Ground::try_from(c).ok()
+        })?);
+    }
+
+    if out.is_empty() {
+        return Err(Error::parse(0, 0, "expected at least one block"));
+    }
+
+    return Ok(out);
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Ground {
+    Ash,
+    Rocks,
+}
+
+impl Ground {
+    pub fn flip(&self) -> Self {
+        match self {
+            Self::Ash => Self::Rocks,
+            Self::Rocks => Self::Ash,
+        }
+    }
+}
+
+impl TryFrom<char> for Ground {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<Self, char> {
+        if value == '.' {
+            return Ok(Self::Rocks);
+        } else if value == '#' {
+            return Ok(Self::Ash);
+        } else {
+            return Err(value);
+        }
+    }
+}
+
+impl Display for Ground {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ash => write!(f, "#"),
+            Self::Rocks => write!(f, "."),
+        }
+    }
+}
+
+/// `size` patterns. Each has a line of reflection, and another that a single smudge hides
+fn generate(generator: &mut Generator) -> String {
+    let mut patterns = vec![];
+
+    for _ in 0..generator.size_or(100).max(1) {
+        let height = generator.range(7..=17);
+        let width = generator.range(7..=17);
+
+        // Columns reflect about the line before `column`, which must leave some columns out, and
+        // rows about the line before `row`
+        let row = generator.range(1..height);
+        let column = loop {
+            let column = generator.range(1..width);
+            if 2 * column != width {
+                break column;
+            }
+        };
+
+        let mut pattern = Grid::new(width, height, '.');
+        for i in 0..height {
+            for j in 0..width {
+                pattern[(i, j)] = *generator.choose(&['.', '#']);
+            }
+        }
+
+        for i in 0..height {
+            for j in column..width.min(2 * column) {
+                pattern[(i, j)] = pattern[(i, 2 * column - 1 - j)];
+            }
+        }
+        for i in row..height.min(2 * row) {
+            for j in 0..width {
+                pattern[(i, j)] = pattern[(2 * row - 1 - i, j)];
+            }
+        }
+
+        // The smudge spoils the rows' reflection, away from the columns that reflect
+        let reach = column.min(width - column);
+        let unreflected: Vec<usize> = (0..width)
+            .filter(|&j| j + reach < column || j >= column + reach)
+            .collect();
+        let i = generator.range(0..height.min(2 * row));
+        let j = *generator.choose(&unreflected);
+        pattern[(i, j)] = match pattern[(i, j)] {
+            '#' => '.',
+            _ => '#',
+        };
+
+        if generator.chance(0.5) {
+            pattern = pattern.transpose();
+        }
+
+        patterns.push(pattern.to_string());
+    }
+
+    return patterns.join("\n\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day13_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 405);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day13_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 400);
+}
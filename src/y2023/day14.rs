@@ -0,0 +1,514 @@
+use std::{collections::HashMap, fmt::Display};
+
+use log::debug;
+
+use crate::{
+    animate::Frames,
+    generate::Generator,
+    grid::Grid,
+    lines, profile,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day14 {
+    grid: Grid<Ground>,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            grid: parse_input(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)));
+    }
+
+    fn animate(&self, frames: &mut dyn Frames) -> Result<bool> {
+        animate(self, frames);
+        return Ok(true);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 14,
+    solution: Day14,
+    input: "data/day14.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day14_demo.txt",
+            expected: Answer::Integer(136),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day14_demo.txt",
+            expected: Answer::Integer(64),
+        },
+    ],
+}
+
+fn part1(day: &Day14) -> Result<usize> {
+    let parsed = tilt_north(&day.grid);
+
+    debug!("{}", parsed);
+
+    return Ok(north_load(&parsed));
+}
+
+/// The load on the north support beams
+fn north_load(parsed: &Grid<Ground>) -> usize {
+    let mut sum = 0;
+
+    for (i, row) in parsed.rows().rev().enumerate() {
+        for g in row {
+            if *g == Ground::Movable {
+                sum += i + 1;
+            }
+        }
+    }
+
+    return sum;
+}
+
+fn tilt_north(grid: &Grid<Ground>) -> Grid<Ground> {
+    let mut parsed = grid.clone();
+
+    // MOVE ROCKS
+    for i in 0..parsed.height() {
+        for j in 0..parsed.width() {
+            let mut running = true;
+            let mut k = i;
+            while running {
+                let tmp = move_rock_north(&mut parsed, k, j);
+
+                if tmp == k {
+                    running = false;
+                } else {
+                    k = tmp;
+                }
+            }
+        }
+    }
+
+    return parsed;
+}
+
+/// The platform after tilting north, then after each spin cycle until the rocks repeat a layout
+fn animate(day: &Day14, frames: &mut dyn Frames) {
+    frames.push("start".to_string(), day.grid.to_string());
+
+    let tilted = tilt_north(&day.grid);
+    frames.push(
+        format!("tilted north, load {}", north_load(&tilted)),
+        tilted.to_string(),
+    );
+
+    let mut parsed = day.grid.clone();
+    let mut cache = HashMap::new();
+
+    for cycle in 1..=TOTAL_CYCLES {
+        perform_cycle(&mut parsed);
+
+        let hash = create_vec_id(&parsed);
+        let load = north_load(&parsed);
+
+        if let Some(previous) = cache.get(&hash) {
+            frames.push(
+                format!(
+                    "cycle {}, load {}, the same as cycle {}",
+                    cycle, load, previous
+                ),
+                parsed.to_string(),
+            );
+            break;
+        }
+
+        frames.push(
+            format!("cycle {}, load {}", cycle, load),
+            parsed.to_string(),
+        );
+        cache.insert(hash, cycle);
+    }
+}
+
+/// The platform tilted north, with rounded rocks in yellow and where they rolled from in brown
+fn render(day: &Day14) -> Picture {
+    let tilted = tilt_north(&day.grid);
+
+    let mut picture = Picture::from_grid(&tilted, |ground| match ground {
+        Ground::Movable => Colour::YELLOW,
+        Ground::Immovable => Colour::GREY,
+        Ground::Empty => Colour::BLACK,
+    });
+
+    for ((i, j), ground) in day.grid.iter() {
+        if *ground == Ground::Movable && tilted[(i, j)] != Ground::Movable {
+            picture.set(i, j, Colour::YELLOW.mix(Colour::BLACK, 0.5));
+        }
+    }
+
+    return picture;
+}
+
+const TOTAL_CYCLES: usize = 1_000_000_000;
+
+fn perform_cycle(parsed: &mut Grid<Ground>) {
+    // North
+    for i in 0..parsed.height() {
+        for j in 0..parsed.width() {
+            let mut running = true;
+            let mut k = i;
+            while running {
+                let tmp = move_rock_north(parsed, k, j);
+
+                if tmp == k {
+                    running = false;
+                } else {
+                    k = tmp;
+                }
+            }
+        }
+    }
+
+    // West
+    for j in 0..parsed.width() {
+        for i in 0..parsed.height() {
+            let mut running = true;
+            let mut k = j;
+            while running {
+                let tmp = move_rock_west(parsed, i, k);
+
+                if tmp == k {
+                    running = false;
+                } else {
+                    k = tmp;
+                }
+            }
+        }
+    }
+
+    // South
+    for i in (0..parsed.height()).rev() {
+        for j in 0..parsed.width() {
+            let mut running = true;
+            let mut k = i;
+            while running {
+                let tmp = move_rock_south(parsed, k, j);
+
+                if tmp == k {
+                    running = false;
+                } else {
+                    k = tmp;
+                }
+            }
+        }
+    }
+
+    // East
+    for j in (0..parsed.width()).rev() {
+        for i in 0..parsed.height() {
+            let mut running = true;
+            let mut k = j;
+            while running {
+                let tmp = move_rock_east(parsed, i, k);
+
+                if tmp == k {
+                    running = false;
+                } else {
+                    k = tmp;
+                }
+            }
+        }
+    }
+}
+
+fn part2(day: &Day14) -> Result<usize> {
+    let span = profile::span("find spin cycle");
+
+    let parsed = spin(&day.grid, TOTAL_CYCLES);
+
+    drop(span);
+
+    // debug!("{}", parsed);
+
+    return Ok(north_load(&parsed));
+}
+
+/// The platform after `cycles` spin cycles, skipping ahead once the rocks repeat a layout
+fn spin(grid: &Grid<Ground>, cycles: usize) -> Grid<Ground> {
+    let mut parsed = grid.clone();
+    let mut cache = HashMap::new();
+
+    // MOVE ROCKS
+    for cycle in 1..=cycles {
+        perform_cycle(&mut parsed);
+
+        let hash = create_vec_id(&parsed);
+
+        if cache.contains_key(&hash) {
+            // We are in a cycle!!!!!
+            let current_cycle = cycle;
+            let previous_cycle_this_config = *cache.get(&hash).unwrap();
+
+            let period = current_cycle - previous_cycle_this_config;
+            let length_through_period = (cycles - previous_cycle_this_config) % period;
+
+            // Need to do length_through_period cycles
+            for _ in 0..length_through_period {
+                perform_cycle(&mut parsed);
+            }
+
+            break;
+        } else {
+            cache.insert(hash, cycle);
+        }
+    }
+
+    return parsed;
+}
+
+fn create_vec_id(vec_chars: &Grid<Ground>) -> [u64; 157] {
+    // Encode the locations of the rounded rocks, assuming grid is 100x100 max.
+    //
+    // NOTE: `64 * 157 > 100 * 100`
+    let mut bits = [0; 157];
+    for (idx, (_, obj)) in vec_chars.iter().enumerate() {
+        if *obj == Ground::Movable {
+            bits[idx / 64] |= 1 << (idx % 64);
+        }
+    }
+    return bits;
+}
+
+fn move_rock_north(parsed: &mut Grid<Ground>, i: usize, j: usize) -> usize {
+    if parsed[(i, j)] != Ground::Movable {
+        return i;
+    }
+
+    if i == 0 {
+        return i;
+    }
+
+    let possible_new_position = i - 1;
+
+    if parsed[(possible_new_position, j)] == Ground::Empty {
+        parsed[(possible_new_position, j)] = Ground::Movable;
+        parsed[(i, j)] = Ground::Empty;
+        return possible_new_position;
+    }
+
+    return i;
+}
+
+fn move_rock_east(parsed: &mut Grid<Ground>, i: usize, j: usize) -> usize {
+    if parsed[(i, j)] != Ground::Movable {
+        return j;
+    }
+    let width = parsed.width();
+    if j == width - 1 {
+        return j;
+    }
+
+    let possible_new_position = j + 1;
+
+    if parsed[(i, possible_new_position)] == Ground::Empty {
+        parsed[(i, possible_new_position)] = Ground::Movable;
+        parsed[(i, j)] = Ground::Empty;
+        return possible_new_position;
+    }
+
+    return j;
+}
+
+fn move_rock_south(parsed: &mut Grid<Ground>, i: usize, j: usize) -> usize {
+    if parsed[(i, j)] != Ground::Movable {
+        return i;
+    }
+    let height = parsed.height();
+    if i == height - 1 {
+        return i;
+    }
+
+    let possible_new_position = i + 1;
+
+    if parsed[(possible_new_position, j)] == Ground::Empty {
+        parsed[(possible_new_position, j)] = Ground::Movable;
+        parsed[(i, j)] = Ground::Empty;
+        return possible_new_position;
+    }
+
+    return i;
+}
+
+fn move_rock_west(parsed: &mut Grid<Ground>, i: usize, j: usize) -> usize {
+    if parsed[(i, j)] != Ground::Movable {
+        return j;
+    }
+    let width = parsed.width();
+    if j == 0 {
+        return j;
+    }
+
+    let possible_new_position = j - 1;
+
+    if parsed[(i, possible_new_position)] == Ground::Empty {
+        parsed[(i, possible_new_position)] = Ground::Movable;
+        parsed[(i, j)] = Ground::Empty;
+        return possible_new_position;
+    }
+
+    return j;
+}
+
+fn parse_input(lines: &Vec<String>) -> Result<Grid<Ground>> {
+    let out = Grid::parse(lines)?;
+
+    // `create_vec_id` packs the grid into a fixed number of bits
+    let cells = out.width() * out.height();
+    if cells > 64 * 157 {
+        return Err(Error::parse(
+            0,
+            0,
+            format!(
+                "the grid has {} cells, at most {} are supported",
+                cells,
+                64 * 157
+            ),
+        ));
+    }
+
+    return Ok(out);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Ground {
+    Movable,
+    Immovable,
+    Empty,
+}
+
+impl TryFrom<char> for Ground {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<Self, char> {
+        if value == 'O' {
+            return Ok(Ground::Movable);
+        } else if value == '#' {
+            return Ok(Ground::Immovable);
+        } else if value == '.' {
+            return Ok(Ground::Empty);
+        } else {
+            return Err(value);
+        }
+    }
+}
+
+impl Display for Ground {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "."),
+            Self::Immovable => write!(f, "#"),
+            Self::Movable => write!(f, "O"),
+        }
+    }
+}
+
+/// A `size` by `size` platform of rocks, at most 100 across
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(100).clamp(1, 100);
+
+    let mut platform = Grid::new(size, size, '.');
+    for i in 0..size {
+        for j in 0..size {
+            platform[(i, j)] = *generator.choose(&['O', 'O', '#', '.', '.', '.', '.', '.']);
+        }
+    }
+
+    return platform.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day14_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 136);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day14_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 64);
+}
+
+/// The platform after `cycles` spin cycles, found by rolling rocks a cell at a time and turning
+/// the platform between tilts, without looking for repeats
+#[cfg(test)]
+fn brute_force_spin(mut rows: Vec<Vec<char>>, cycles: usize) -> Vec<Vec<char>> {
+    for _ in 0..cycles * 4 {
+        // Tilt north
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for i in 1..rows.len() {
+                for j in 0..rows[i].len() {
+                    if rows[i][j] == 'O' && rows[i - 1][j] == '.' {
+                        rows[i][j] = '.';
+                        rows[i - 1][j] = 'O';
+                        moved = true;
+                    }
+                }
+            }
+        }
+
+        // Turn clockwise, so west becomes north
+        let height = rows.len();
+        let width = rows[0].len();
+        rows = (0..width)
+            .map(|i| (0..height).map(|j| rows[height - 1 - j][i]).collect())
+            .collect();
+    }
+
+    return rows;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_spin_matches_brute_force(
+        rows in proptest::strategy::Strategy::prop_flat_map(1..=6usize, |size| {
+            proptest::collection::vec(
+                proptest::collection::vec(proptest::sample::select(vec!['O', '#', '.', '.']), size),
+                size,
+            )
+        }),
+        cycles in 0..=40usize,
+    ) {
+        let input: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+        let grid = parse_input(&input).unwrap();
+
+        let expected: Vec<String> = brute_force_spin(rows, cycles)
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect();
+
+        proptest::prop_assert_eq!(spin(&grid, cycles).to_string(), expected.join("\n"));
+    }
+}
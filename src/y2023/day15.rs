@@ -0,0 +1,225 @@
+use std::{collections::HashMap, hash};
+
+use log::{debug, trace};
+
+use crate::{
+    generate::Generator,
+    lines,
+    parse::{identifier, integer, opt, parse_line, preceded, separated, tag, Cursor, Parser},
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day15 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            lines: lines(input),
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 15,
+    solution: Day15,
+    input: "data/day15.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day15_demo.txt",
+            expected: Answer::Integer(1320),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day15_demo.txt",
+            expected: Answer::Integer(145),
+        },
+    ],
+}
+
+fn part1(day: &Day15) -> Result<usize> {
+    let lines = &day.lines;
+    let line = lines.concat();
+
+    let split: Vec<&str> = line.split(",").collect();
+    let mut sum = 0;
+    for chunk in split {
+        sum += get_hash(chunk)
+    }
+
+    return Ok(sum);
+}
+
+fn part2(day: &Day15) -> Result<usize> {
+    let lines = &day.lines;
+    let line = lines.concat();
+
+    let steps = parse_line(0, &line, separated(step(), tag(",")))?;
+
+    let mut cache: HashMap<usize, Vec<(String, usize)>> = HashMap::new();
+
+    for step in steps {
+        match step {
+            Step::Add(label, focal_power) => {
+                let hash = get_hash(label);
+                let label = label.to_string();
+
+                if cache.contains_key(&hash) {
+                    let this_box = cache.get_mut(&hash).unwrap();
+
+                    let mut index = None;
+
+                    for (i, v) in this_box.iter().enumerate() {
+                        if v.0 == label {
+                            index = Some(i);
+                            break;
+                        }
+                    }
+
+                    if index.is_none() {
+                        this_box.push((label, focal_power));
+                    } else {
+                        let this_lense = this_box.get_mut(index.unwrap()).unwrap();
+                        this_lense.1 = focal_power;
+                    }
+                } else {
+                    cache.insert(hash, vec![(label, focal_power)]);
+                }
+            }
+            Step::Remove(label) => {
+                let hash = get_hash(label);
+
+                if cache.contains_key(&hash) {
+                    let this_box = cache.get_mut(&hash).unwrap();
+
+                    let mut index = None;
+
+                    for (i, v) in this_box.iter().enumerate() {
+                        if v.0 == label {
+                            index = Some(i);
+                            break;
+                        }
+                    }
+
+                    if index.is_some() {
+                        this_box.remove(index.unwrap());
+                    }
+                }
+            }
+        }
+
+        for (box_num, lenses) in cache.iter() {
+            trace!("Box {} contains {:?}", box_num, lenses);
+        }
+    }
+
+    let focal_power = get_focusing_power(&cache);
+
+    return Ok(focal_power);
+}
+
+enum Step<'a> {
+    /// Puts a lens with a focal length in the label's box
+    Add(&'a str, usize),
+    /// Takes the labelled lens out of its box
+    Remove(&'a str),
+}
+
+/// `rn=1` or `cm-`
+fn step<'a>() -> impl Parser<'a, Step<'a>> {
+    return |cursor: &mut Cursor<'a>| {
+        let label = identifier()(cursor)?;
+
+        if let Some(focal_power) = opt(preceded(tag("="), integer()))(cursor)? {
+            return Ok(Step::Add(label, focal_power));
+        }
+
+        if tag("-")(cursor).is_err() {
+            return Err(cursor.error(format!("expected `=` or `-` after `{}`", label)));
+        }
+
+        return Ok(Step::Remove(label));
+    };
+}
+
+fn get_hash(chunk: &str) -> usize {
+    let mut hash = 0;
+
+    for c in chunk.chars() {
+        let ascii = c as u8;
+
+        hash += ascii as usize;
+
+        hash *= 17;
+
+        hash %= 256;
+    }
+    trace!("hashing {} with value {}", chunk, hash);
+    return hash;
+}
+
+fn get_focusing_power(cache: &HashMap<usize, Vec<(String, usize)>>) -> usize {
+    let mut sum = 0;
+    for (box_num, lenses) in cache.iter() {
+        debug!("Box {} contains {:?}", box_num, lenses);
+        for (i, lens) in lenses.iter().enumerate() {
+            let focal_power = (box_num + 1) * (i + 1) * lens.1;
+            sum += focal_power;
+        }
+    }
+
+    return sum;
+}
+
+/// A sequence of `size` steps over a few hundred labels
+fn generate(generator: &mut Generator) -> String {
+    let steps = generator.size_or(4000).max(1);
+
+    let mut labels = vec![];
+    for len in 2..=6 {
+        labels.extend(generator.names((steps / 40 + 1).min(500), len, &[]));
+    }
+
+    let mut sequence = vec![];
+    for _ in 0..steps {
+        let label = generator.choose(&labels).clone();
+
+        if generator.chance(0.3) {
+            sequence.push(format!("{}-", label));
+        } else {
+            sequence.push(format!("{}={}", label, generator.range(1..=9)));
+        }
+    }
+
+    return sequence.join(",");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day15_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 1320)
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day15_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 145)
+}
@@ -0,0 +1,508 @@
+use std::{collections::HashSet, fmt::Display};
+
+use log::debug;
+
+use crate::{
+    animate::Frames,
+    generate::Generator,
+    geometry::Direction,
+    grid::Grid,
+    lines,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day16 {
+    grid: Grid<Tile>,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            grid: parse_input(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)));
+    }
+
+    fn animate(&self, frames: &mut dyn Frames) -> Result<bool> {
+        animate(self, frames);
+        return Ok(true);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 16,
+    solution: Day16,
+    input: "data/day16.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day16_demo.txt",
+            expected: Answer::Integer(46),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day16_demo.txt",
+            expected: Answer::Integer(51),
+        },
+    ],
+}
+
+fn part1(day: &Day16) -> Result<usize> {
+    let grid = &day.grid;
+
+    let initial_direction = (0, 0, Direction::East);
+
+    return Ok(count_energised(initial_direction, &grid));
+}
+
+fn part2(day: &Day16) -> Result<usize> {
+    let grid = &day.grid;
+
+    let mut energised = vec![];
+
+    let mut starting_positions = vec![];
+
+    let height = grid.height();
+    let width = grid.width();
+
+    // South Facing Start Directions
+    for i in 0..width {
+        starting_positions.push((0 as i32, i as i32, Direction::South));
+    }
+
+    // East Facing Start Directions
+    for i in 0..height {
+        starting_positions.push((i as i32, 0 as i32, Direction::East));
+    }
+
+    // North Facing Start Directions
+    for i in 0..width {
+        starting_positions.push(((height - 1) as i32, i as i32, Direction::North));
+    }
+
+    // West Facing Start Directions
+    for i in 0..height {
+        starting_positions.push((i as i32, (width - 1) as i32, Direction::West));
+    }
+
+    for starting_position in starting_positions {
+        energised.push(count_energised(starting_position, &grid));
+    }
+
+    return Ok(*energised.iter().max().unwrap());
+}
+
+fn count_energised(initial_direction: (i32, i32, Direction), grid: &Grid<Tile>) -> usize {
+    return energised(initial_direction, grid).len();
+}
+
+/// The tiles a beam passes through, each once
+fn energised(initial_direction: (i32, i32, Direction), grid: &Grid<Tile>) -> Vec<(i32, i32)> {
+    let mut energised_tiles = vec![];
+
+    let mut beams = vec![initial_direction];
+
+    let height = grid.height() as i32;
+    let width = grid.width() as i32;
+
+    while beams.len() > 0 {
+        let beam = &beams[0];
+        let tile = &grid[(beam.0 as usize, beam.1 as usize)];
+
+        energised_tiles.push(beam.clone());
+
+        let (new_i, new_j, new_direction, secondary_i, secondary_j, secondary_direction) =
+            beam_logic(tile, beam);
+
+        let mut change = true;
+
+        if new_i < 0 || new_i >= height {
+            // Out of bounds
+            beams.remove(0);
+            change = false;
+        }
+
+        if (new_j < 0 || new_j >= width) && change {
+            // Out of bounds
+            beams.remove(0);
+            change = false;
+        }
+
+        if (energised_tiles.contains(&(new_i, new_j, new_direction))) && change {
+            // Already been here - in a loop
+            beams.remove(0);
+            change = false;
+        }
+
+        if change {
+            beams[0] = (new_i, new_j, new_direction);
+        }
+
+        if secondary_i.is_some() {
+            let i = secondary_i.unwrap();
+            let j = secondary_j.unwrap();
+            let d = secondary_direction.unwrap();
+
+            if i < 0 || i >= height {
+                // Out of bounds
+                continue;
+            }
+
+            if j < 0 || j >= width {
+                continue;
+            }
+
+            if energised_tiles.contains(&(i, j, d)) {
+                continue;
+            }
+
+            beams.push((i, j, d));
+        }
+    }
+
+    // display_energised(&energised_tiles, height as usize, width as usize);
+
+    let mut locs = vec![];
+
+    for loc in energised_tiles {
+        if !locs.contains(&(loc.0, loc.1)) {
+            locs.push((loc.0, loc.1));
+        }
+    }
+
+    return locs;
+}
+
+/// Part 1's beams moving a tile at a time, as arrows, leaving the tiles they energise as `#`
+fn animate(day: &Day16, frames: &mut dyn Frames) {
+    let grid = &day.grid;
+    let height = grid.height() as i32;
+    let width = grid.width() as i32;
+
+    let mut seen = HashSet::new();
+    let mut energised = Grid::new(grid.width(), grid.height(), false);
+    let mut beams = vec![(0, 0, Direction::East)];
+    let mut step = 0;
+
+    while !beams.is_empty() {
+        for beam in &beams {
+            seen.insert(*beam);
+            energised[(beam.0 as usize, beam.1 as usize)] = true;
+        }
+
+        let mut text = grid.map(|tile| tile.to_string());
+        for ((i, j), &on) in energised.iter() {
+            if on && matches!(grid[(i, j)], Tile::EmptySpace) {
+                text[(i, j)] = "#".to_string();
+            }
+        }
+        for beam in &beams {
+            text[(beam.0 as usize, beam.1 as usize)] = beam.2.to_string();
+        }
+
+        let count = energised.iter().filter(|(_, &on)| on).count();
+        frames.push(
+            format!(
+                "step {}, {} beams, {} tiles energised",
+                step,
+                beams.len(),
+                count
+            ),
+            text.to_string(),
+        );
+
+        let mut next = vec![];
+        for beam in &beams {
+            let (i, j, direction, secondary_i, secondary_j, secondary_direction) =
+                beam_logic(&grid[(beam.0 as usize, beam.1 as usize)], beam);
+
+            let mut moved = vec![(i, j, direction)];
+            if let (Some(i), Some(j), Some(d)) = (secondary_i, secondary_j, secondary_direction) {
+                moved.push((i, j, d));
+            }
+
+            for beam in moved {
+                let inside = beam.0 >= 0 && beam.0 < height && beam.1 >= 0 && beam.1 < width;
+                if inside && !seen.contains(&beam) && !next.contains(&beam) {
+                    next.push(beam);
+                }
+            }
+        }
+
+        beams = next;
+        step += 1;
+    }
+}
+
+/// The tiles energised by part 1's beam in yellow, and the mirrors and splitters it hits in white
+fn render(day: &Day16) -> Picture {
+    let mut picture = Picture::new(day.grid.width(), day.grid.height(), Colour::BLACK);
+
+    let tiles = energised((0, 0, Direction::East), &day.grid);
+    picture.highlight(
+        tiles.iter().map(|&(i, j)| (i as usize, j as usize)),
+        Colour::YELLOW.mix(Colour::BLACK, 0.4),
+    );
+
+    for ((i, j), tile) in day.grid.iter() {
+        if matches!(tile, Tile::EmptySpace) {
+            continue;
+        }
+
+        match tiles.contains(&(i as i32, j as i32)) {
+            true => picture.set(i, j, Colour::WHITE),
+            false => picture.set(i, j, Colour::GREY),
+        }
+    }
+
+    return picture;
+}
+
+fn beam_logic(
+    tile: &Tile,
+    beam: &(i32, i32, Direction),
+) -> (
+    i32,
+    i32,
+    Direction,
+    Option<i32>,
+    Option<i32>,
+    Option<Direction>,
+) {
+    let (new_i, new_j, new_direction, secondary_i, secondary_j, secondary_direction) = match beam.2
+    {
+        Direction::North => match tile {
+            Tile::EmptySpace => (beam.0 - 1, beam.1, beam.2, None, None, None),
+            Tile::ForwardSlash => (beam.0, beam.1 + 1, Direction::East, None, None, None),
+            Tile::BackSlash => (beam.0, beam.1 - 1, Direction::West, None, None, None),
+            Tile::VerticalSplitter => (beam.0 - 1, beam.1, beam.2, None, None, None),
+            Tile::HorizontalSplitter => (
+                beam.0,
+                beam.1 + 1,
+                Direction::East,
+                Some(beam.0),
+                Some(beam.1 - 1),
+                Some(Direction::West),
+            ),
+        },
+        Direction::East => match tile {
+            Tile::EmptySpace => (beam.0, beam.1 + 1, beam.2, None, None, None),
+            Tile::ForwardSlash => (beam.0 - 1, beam.1, Direction::North, None, None, None),
+            Tile::BackSlash => (beam.0 + 1, beam.1, Direction::South, None, None, None),
+            Tile::VerticalSplitter => (
+                beam.0 - 1,
+                beam.1,
+                Direction::North,
+                Some(beam.0 + 1),
+                Some(beam.1),
+                Some(Direction::South),
+            ),
+            Tile::HorizontalSplitter => (beam.0, beam.1 + 1, beam.2, None, None, None),
+        },
+        Direction::South => match tile {
+            Tile::EmptySpace => (beam.0 + 1, beam.1, beam.2, None, None, None),
+            Tile::ForwardSlash => (beam.0, beam.1 - 1, Direction::West, None, None, None),
+            Tile::BackSlash => (beam.0, beam.1 + 1, Direction::East, None, None, None),
+            Tile::VerticalSplitter => (beam.0 + 1, beam.1, beam.2, None, None, None),
+            Tile::HorizontalSplitter => (
+                beam.0,
+                beam.1 - 1,
+                Direction::West,
+                Some(beam.0),
+                Some(beam.1 + 1),
+                Some(Direction::East),
+            ),
+        },
+        Direction::West => match tile {
+            Tile::EmptySpace => (beam.0, beam.1 - 1, beam.2, None, None, None),
+            Tile::ForwardSlash => (beam.0 + 1, beam.1, Direction::South, None, None, None),
+            Tile::BackSlash => (beam.0 - 1, beam.1, Direction::North, None, None, None),
+            Tile::VerticalSplitter => (
+                beam.0 + 1,
+                beam.1,
+                Direction::South,
+                Some(beam.0 - 1),
+                Some(beam.1),
+                Some(Direction::North),
+            ),
+            Tile::HorizontalSplitter => (beam.0, beam.1 - 1, beam.2, None, None, None),
+        },
+    };
+
+    return (
+        new_i,
+        new_j,
+        new_direction,
+        secondary_i,
+        secondary_j,
+        secondary_direction,
+    );
+}
+
+fn parse_input(lines: &Vec<String>) -> Result<Grid<Tile>> {
+    let out = Grid::parse(lines)?;
+
+    if out.width() == 0 {
+        return Err(Error::parse(0, 0, "expected at least one tile"));
+    }
+
+    return Ok(out);
+}
+
+enum Tile {
+    EmptySpace,
+    ForwardSlash,
+    BackSlash,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<Self, char> {
+        return if value == '.' {
+            Ok(Self::EmptySpace)
+        } else if value == '/' {
+            Ok(Self::ForwardSlash)
+        } else if value == '\\' {
+            Ok(Self::BackSlash)
+        } else if value == '|' {
+            Ok(Self::VerticalSplitter)
+        } else if value == '-' {
+            Ok(Self::HorizontalSplitter)
+        } else {
+            Err(value)
+        };
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::EmptySpace => write!(f, "."),
+            Tile::ForwardSlash => write!(f, "/"),
+            Tile::BackSlash => write!(f, "\\"),
+            Tile::VerticalSplitter => write!(f, "|"),
+            Tile::HorizontalSplitter => write!(f, "-"),
+        }
+    }
+}
+
+fn display_energised(energised: &Vec<(i32, i32, Direction)>, height: usize, width: usize) {
+    let mut locs = Grid::new(width, height, '.');
+
+    for loc in energised {
+        locs[(loc.0 as usize, loc.1 as usize)] = '#';
+    }
+
+    debug!("{}", locs);
+}
+
+/// A `size` by `size` contraption of mirrors and splitters
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(110).max(1);
+
+    let mut contraption = Grid::new(size, size, '.');
+    for i in 0..size {
+        for j in 0..size {
+            if generator.chance(0.1) {
+                contraption[(i, j)] = *generator.choose(&['/', '\\', '|', '-']);
+            }
+        }
+    }
+
+    return contraption.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day16_demo.txt";
+    let energised = part1(&crate::load(path)).unwrap();
+    assert_eq!(energised, 46);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day16_demo.txt";
+    let energised = part2(&crate::load(path)).unwrap();
+    assert_eq!(energised, 51);
+}
+
+/// The most tiles any beam entering from an edge energises, found by following each beam
+/// through every tile and direction it reaches
+#[cfg(test)]
+fn brute_force_best(grid: &Grid<Tile>) -> usize {
+    let (height, width) = (grid.height() as i64, grid.width() as i64);
+
+    let mut entries = vec![];
+    for j in 0..width {
+        entries.push((0, j, (1, 0)));
+        entries.push((height - 1, j, (-1, 0)));
+    }
+    for i in 0..height {
+        entries.push((i, 0, (0, 1)));
+        entries.push((i, width - 1, (0, -1)));
+    }
+
+    let mut best = 0;
+
+    for entry in entries {
+        let mut seen = HashSet::new();
+        let mut stack = vec![entry];
+
+        while let Some((i, j, (di, dj))) = stack.pop() {
+            if i < 0 || i >= height || j < 0 || j >= width || !seen.insert((i, j, (di, dj))) {
+                continue;
+            }
+
+            let turns = match (&grid[(i as usize, j as usize)], di) {
+                (Tile::ForwardSlash, _) => vec![(-dj, -di)],
+                (Tile::BackSlash, _) => vec![(dj, di)],
+                (Tile::VerticalSplitter, 0) => vec![(-1, 0), (1, 0)],
+                (Tile::HorizontalSplitter, -1 | 1) => vec![(0, -1), (0, 1)],
+                _ => vec![(di, dj)],
+            };
+
+            for (di, dj) in turns {
+                stack.push((i + di, j + dj, (di, dj)));
+            }
+        }
+
+        let tiles: HashSet<(i64, i64)> = seen.iter().map(|&(i, j, _)| (i, j)).collect();
+        best = best.max(tiles.len());
+    }
+
+    return best;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, size in 1..=10usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let day = Day16::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_best(&day.grid));
+    }
+}
@@ -0,0 +1,348 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::fs;
+use std::hash::{Hash, Hasher};
+
+use log::debug;
+
+use crate::{
+    generate::Generator,
+    geometry::{Direction, Point},
+    grid::Grid,
+    lines,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day17 {
+    grid: Grid<u32>,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            grid: parse_input(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)?));
+    }
+}
+
+register! {
+    year: 2023,
+    day: 17,
+    solution: Day17,
+    input: "data/day17.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day17_demo.txt",
+            expected: Answer::Integer(102),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day17_demo.txt",
+            expected: Answer::Integer(94),
+        },
+    ],
+}
+
+fn part1(day: &Day17) -> Result<u64> {
+    let grid = &day.grid;
+
+    let heat = dijkstra_shortest_path(&grid, 3, 0)?;
+
+    return Ok(heat);
+}
+
+fn part2(day: &Day17) -> Result<u64> {
+    let grid = &day.grid;
+
+    let heat = dijkstra_shortest_path(&grid, 10, 4)?;
+
+    return Ok(heat);
+}
+
+/// The direction as a red arrow, for drawing the path over the grid
+fn repr(direction: Direction) -> String {
+    return format!("\x1b[31m{}\x1b[0m", direction);
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    location: Point,
+    weight: u32,
+    direction: Direction,
+    count: u8,
+    previous: Option<Box<Node>>,
+}
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.location.hash(state);
+        self.direction.hash(state);
+        self.count.hash(state);
+    }
+}
+
+impl PartialEq<Self> for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.direction == other.direction
+            && self.count == other.count
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd<Self> for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.weight.cmp(&self.weight)
+    }
+}
+
+fn parse_input(lines: &Vec<String>) -> Result<Grid<u32>> {
+    let out = Grid::parse_with(lines, |c| c.to_digit(10))?;
+
+    if out.width() == 0 {
+        return Err(Error::parse(0, 0, "expected at least one digit"));
+    }
+
+    return Ok(out);
+}
+
+fn dijkstra_shortest_path(grid: &Grid<u32>, max_step_limit: u8, min_step_limit: u8) -> Result<u64> {
+    let node = least_heat_loss(grid, max_step_limit, min_step_limit)?;
+
+    let mut data = grid.map(|val| val.to_string());
+    let mut curr: &Node = &node;
+    data[curr.location] = repr(curr.direction);
+    while let Some(ref prev) = curr.previous {
+        if prev.location != Point::new(0, 0) {
+            data[prev.location] = repr(prev.direction);
+        }
+        curr = prev;
+    }
+
+    debug!("{}", data);
+
+    return Ok(node.weight as u64);
+}
+
+/// The node at the bottom right reached with the least heat loss, linked back to the start
+fn least_heat_loss(grid: &Grid<u32>, max_step_limit: u8, min_step_limit: u8) -> Result<Node> {
+    let mut heap: BinaryHeap<Node> = BinaryHeap::new();
+    let height = grid.height();
+    let width = grid.width();
+    let end_location = Point::new(width as i64 - 1, height as i64 - 1);
+    let mut visited: HashSet<Node> = HashSet::new();
+    // The crucible may set off east or south
+    for direction in [Direction::East, Direction::South] {
+        heap.push(Node {
+            location: Point::new(0, 0),
+            weight: 0,
+            direction,
+            count: 0,
+            previous: None,
+        });
+    }
+    let mut end_node = None;
+    while let Some(node) = heap.pop() {
+        if node.location == end_location {
+            if node.count < min_step_limit {
+                continue;
+            }
+            end_node = Some(node);
+            break;
+        }
+        if visited.contains(&node) {
+            continue;
+        }
+        for direction in [
+            Direction::East,
+            Direction::West,
+            Direction::North,
+            Direction::South,
+        ] {
+            // Turning back is not allowed
+            if direction == node.direction.reverse() {
+                continue;
+            }
+
+            let count = if direction == node.direction {
+                if node.count >= max_step_limit {
+                    continue;
+                }
+                node.count + 1
+            } else {
+                if node.count < min_step_limit {
+                    continue;
+                }
+                1
+            };
+
+            let location = node.location.step(direction);
+            let heat = match grid.get_point(location) {
+                Some(x) => *x,
+                None => continue,
+            };
+
+            let next_node = Node {
+                location,
+                weight: node.weight + heat,
+                direction,
+                count,
+                previous: Some(Box::new(node.clone())),
+            };
+            heap.push(next_node);
+        }
+        visited.insert(node);
+    }
+    drop(visited);
+
+    return end_node.ok_or_else(|| Error::unsolvable("cannot reach the end of the grid"));
+}
+
+/// The cells from the start to `node`, as rows and columns
+fn path(node: &Node) -> Vec<(usize, usize)> {
+    let mut cells = vec![(node.location.y as usize, node.location.x as usize)];
+
+    let mut curr = node;
+    while let Some(ref prev) = curr.previous {
+        cells.push((prev.location.y as usize, prev.location.x as usize));
+        curr = prev;
+    }
+
+    cells.reverse();
+    return cells;
+}
+
+/// Heat loss from dark to bright red, with part 1's path in white and part 2's in blue
+fn render(day: &Day17) -> Result<Picture> {
+    let grid = &day.grid;
+
+    let mut picture = Picture::from_grid(grid, |&heat| {
+        Colour::BLACK.mix(Colour::RED, heat as f64 / 9.0)
+    });
+    picture.path(path(&least_heat_loss(grid, 3, 0)?), Colour::WHITE);
+    picture.path(path(&least_heat_loss(grid, 10, 4)?), Colour::BLUE);
+
+    return Ok(picture);
+}
+
+/// A `size` by `size` map of heat loss, at least 5 across
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(141).max(5);
+
+    let mut map = Grid::new(size, size, 0);
+    for i in 0..size {
+        for j in 0..size {
+            map[(i, j)] = generator.range(1..=9);
+        }
+    }
+
+    return map.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day17_demo.txt";
+    let heat = part1(&crate::load(path)).unwrap();
+    assert_eq!(heat, 102);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day17_demo.txt";
+    let heat = part2(&crate::load(path)).unwrap();
+    assert_eq!(heat, 94);
+}
+
+/// The least heat loss from the top left to the bottom right, found by relaxing every way of
+/// arriving at every block until none of them improves
+#[cfg(test)]
+fn brute_force_heat_loss(grid: &Grid<u32>, max_step_limit: u8, min_step_limit: u8) -> u32 {
+    use std::collections::HashMap;
+
+    let end = Point::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+
+    // The least heat loss found so far to arrive at a block going some way, some steps in a row
+    let mut best: HashMap<(Point, Direction, u8), u32> = HashMap::new();
+    for direction in [Direction::East, Direction::South] {
+        let location = Point::new(0, 0).step(direction);
+        if let Some(&heat) = grid.get_point(location) {
+            best.insert((location, direction, 1), heat);
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for ((location, direction, count), heat) in best.clone() {
+            let mut turns = vec![];
+            if count < max_step_limit {
+                turns.push((direction, count + 1));
+            }
+            if count >= min_step_limit {
+                turns.push((direction.turn_left(), 1));
+                turns.push((direction.turn_right(), 1));
+            }
+
+            for (next, count) in turns {
+                let location = location.step(next);
+                let Some(&loss) = grid.get_point(location) else {
+                    continue;
+                };
+
+                let known = best.entry((location, next, count)).or_insert(u32::MAX);
+                if heat + loss < *known {
+                    *known = heat + loss;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    return best
+        .into_iter()
+        .filter(|((location, _, count), _)| *location == end && *count >= min_step_limit)
+        .map(|(_, heat)| heat)
+        .min()
+        .unwrap();
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_heat_loss_matches_brute_force(seed in proptest::num::u64::ANY, size in 5..=8usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let day = Day17::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part1(&day).unwrap() as u32, brute_force_heat_loss(&day.grid, 3, 0));
+        proptest::prop_assert_eq!(part2(&day).unwrap() as u32, brute_force_heat_loss(&day.grid, 10, 4));
+    }
+}
@@ -0,0 +1,485 @@
+use log::{debug, log_enabled, trace, Level};
+
+use crate::{
+    generate::Generator,
+    geometry::Direction,
+    grid::Grid,
+    parse::{
+        delimited, integer, map, pair, parse_lines, spaces, tag, take_while, terminated, try_map,
+        Parser,
+    },
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day18 {
+    instructions: Vec<DiggerInstructions>,
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            instructions: parse_lines(input, instruction())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)));
+    }
+}
+
+register! {
+    year: 2023,
+    day: 18,
+    solution: Day18,
+    input: "data/day18.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day18_demo.txt",
+            expected: Answer::Integer(62),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day18_demo.txt",
+            expected: Answer::Integer(952408144115),
+        },
+    ],
+}
+
+fn part1(day: &Day18) -> Result<i32> {
+    let edge = dig_trench(&day.instructions);
+    let (min_i, min_j, max_i, max_j) = bounds(&edge);
+
+    debug!("There are {} instructions", day.instructions.len());
+
+    // Drawing the lagoon checks every cell against the whole edge, so only do it when wanted
+    if log_enabled!(Level::Trace) {
+        display_edge(min_i, min_j, max_i, max_j, &edge);
+    }
+
+    let mut count = 0;
+
+    for i in min_i..=max_i {
+        for j in min_j..=max_j {
+            if in_or_on_polygon((i, j), &edge) {
+                count += 1;
+            }
+        }
+    }
+
+    return Ok(count);
+}
+
+/// Every cube dug for the trench in part 1, starting and ending at the origin
+fn dig_trench(insturctions: &[DiggerInstructions]) -> Vec<(i32, i32)> {
+    let mut i = 0;
+    let mut j = 0;
+
+    let mut edge = vec![(i, j)];
+
+    for instruction in insturctions {
+        for _ in 0..instruction.distance {
+            let delta = instruction.direction.delta();
+            i += delta.y as i32;
+            j += delta.x as i32;
+
+            edge.push((i, j));
+        }
+    }
+
+    return edge;
+}
+
+/// The smallest and largest row and column of the trench
+fn bounds(edge: &[(i32, i32)]) -> (i32, i32, i32, i32) {
+    let min_i = edge.iter().map(|p| p.0).min().unwrap_or(0);
+    let min_j = edge.iter().map(|p| p.1).min().unwrap_or(0);
+    let max_i = edge.iter().map(|p| p.0).max().unwrap_or(0);
+    let max_j = edge.iter().map(|p| p.1).max().unwrap_or(0);
+
+    return (min_i, min_j, max_i, max_j);
+}
+
+/// Part 1's lagoon, with the trench in red and the dug out interior in brown
+fn render(day: &Day18) -> Picture {
+    let edge = dig_trench(&day.instructions);
+    let (min_i, min_j, max_i, max_j) = bounds(&edge);
+    let height = (max_i - min_i + 1) as usize;
+    let width = (max_j - min_j + 1) as usize;
+
+    let cells: Vec<(usize, usize)> = edge
+        .iter()
+        .map(|&(i, j)| ((i - min_i) as usize, (j - min_j) as usize))
+        .collect();
+
+    // Flood the ground outside the trench from the border, so that what is left is dug out
+    let mut outside = Grid::new(width, height, false);
+    let mut trench = Grid::new(width, height, false);
+    for &(i, j) in &cells {
+        trench[(i, j)] = true;
+    }
+
+    let mut stack: Vec<(usize, usize)> = trench
+        .iter()
+        .map(|(p, _)| p)
+        .filter(|&(i, j)| i == 0 || j == 0 || i == height - 1 || j == width - 1)
+        .filter(|&p| !trench[p])
+        .collect();
+    while let Some(p) = stack.pop() {
+        if outside[p] || trench[p] {
+            continue;
+        }
+
+        outside[p] = true;
+        stack.extend(outside.neighbours_4(p.0, p.1));
+    }
+
+    let mut picture = Picture::from_grid(&outside, |&outside| match outside {
+        true => Colour::BLACK,
+        false => Colour(120, 80, 40),
+    });
+    picture.path(cells, Colour::RED);
+
+    return picture;
+}
+
+fn part2(day: &Day18) -> Result<i64> {
+    let insturctions = &day.instructions;
+    let mut i = 0;
+    let mut j = 0;
+
+    let mut edge = vec![(i, j)];
+
+    let mut boundary = 0;
+
+    for instruction in insturctions {
+        let distance = instruction.colour_distance;
+        let delta = instruction.colour_direction.delta() * distance;
+
+        boundary += distance;
+
+        i += delta.y;
+        j += delta.x;
+
+        edge.push((i, j));
+    }
+
+    let pseudoarea: i64 = edge
+        .iter()
+        .zip(edge.iter().cycle().skip(1))
+        .map(|(a, b)| a.0 * b.1 - a.1 * b.0)
+        .sum();
+    let area = pseudoarea.abs() / 2 + boundary / 2 + 1;
+
+    return Ok(area);
+}
+
+fn in_or_on_polygon(point: (i32, i32), polygon: &Vec<(i32, i32)>) -> bool {
+    if on_polygon(point, polygon) {
+        return true;
+    }
+
+    return in_polygon(point, polygon);
+}
+
+fn on_polygon(point: (i32, i32), polygon: &Vec<(i32, i32)>) -> bool {
+    if polygon.contains(&point) {
+        return true;
+    }
+
+    return false;
+}
+
+fn in_polygon(point: (i32, i32), polygon: &Vec<(i32, i32)>) -> bool {
+    let mut inside = false;
+    let n = polygon.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+
+        let vi = polygon[i];
+        let vj = polygon[j];
+
+        // Check if the ray intersects with the polygon edge
+        if ((vi.0 > point.0) != (vj.0 > point.0))
+            && (point.1 < (vj.1 - vi.1) * (point.0 - vi.0) / (vj.0 - vi.0) + vi.1)
+        {
+            inside = !inside;
+        }
+    }
+
+    return inside;
+}
+
+fn display(min_i: i32, min_j: i32, max_i: i32, max_j: i32, polygon: &Vec<(i32, i32)>) {
+    let mut out = String::new();
+
+    for i in min_i..=max_i {
+        for j in min_j..=max_j {
+            if on_polygon((i, j), polygon) {
+                out.push('#');
+            } else if in_polygon((i, j), polygon) {
+                out.push('^');
+            } else {
+                out.push('.');
+            }
+        }
+
+        out.push('\n');
+    }
+
+    trace!("{}", out);
+}
+
+fn display_edge(min_i: i32, min_j: i32, max_i: i32, max_j: i32, polygon: &Vec<(i32, i32)>) {
+    let mut out = String::new();
+
+    for i in min_i..=max_i {
+        for j in min_j..=max_j {
+            if on_polygon((i, j), polygon) {
+                out.push('#');
+            } else {
+                out.push('.');
+            }
+        }
+
+        out.push('\n');
+    }
+
+    trace!("{}", out);
+}
+
+struct DiggerInstructions {
+    pub direction: Direction,
+    pub distance: i32,
+    /// The real direction and distance, hidden in the colour code
+    pub colour_direction: Direction,
+    pub colour_distance: i64,
+}
+
+/// `R 6 (#70c710)`
+fn instruction<'a>() -> impl Parser<'a, DiggerInstructions> {
+    let direction = try_map(take_while("a direction", |c| c != ' '), |dir| {
+        parse_direction(dir).ok_or_else(|| format!("unknown direction `{}`", dir))
+    });
+
+    let hex = try_map(take_while("a colour", |c| c.is_ascii_hexdigit()), |hex| {
+        if hex.len() != 6 {
+            return Err(format!(
+                "expected a colour of 6 hex digits, found `{}`",
+                hex
+            ));
+        }
+
+        let distance = i64::from_str_radix(&hex[0..5], 16).map_err(|e| e.to_string())?;
+        let direction = parse_colour_direction(&hex[5..6])
+            .ok_or_else(|| format!("unknown direction `{}` in colour `{}`", &hex[5..6], hex))?;
+
+        return Ok((direction, distance));
+    });
+
+    return map(
+        pair(
+            terminated(direction, spaces()),
+            pair(
+                terminated(integer(), spaces()),
+                delimited(tag("(#"), hex, tag(")")),
+            ),
+        ),
+        |(direction, (distance, (colour_direction, colour_distance)))| DiggerInstructions {
+            direction,
+            distance,
+            colour_direction,
+            colour_distance,
+        },
+    );
+}
+
+fn parse_direction(value: &str) -> Option<Direction> {
+    if value == "U" {
+        return Some(Direction::North);
+    } else if value == "R" {
+        return Some(Direction::East);
+    } else if value == "D" {
+        return Some(Direction::South);
+    } else if value == "L" {
+        return Some(Direction::West);
+    } else {
+        return None;
+    }
+}
+
+/// The last digit of the colour code: 0 means R, 1 means D, 2 means L, and 3 means U
+fn parse_colour_direction(value: &str) -> Option<Direction> {
+    if value == "0" {
+        return Some(Direction::East);
+    } else if value == "1" {
+        return Some(Direction::South);
+    } else if value == "2" {
+        return Some(Direction::West);
+    } else if value == "3" {
+        return Some(Direction::North);
+    } else {
+        return None;
+    }
+}
+
+/// A dig plan tracing the outline of a random shape on a `size` by `size` grid of corners, at
+/// most 1000 across. The colour codes trace the same shape at a much larger scale, as in the
+/// puzzle input
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(40).clamp(3, 1000);
+    let path = generator.loop_path(size, size);
+
+    // Only the corners of the outline matter, with random gaps between the grid lines
+    let corners: Vec<(usize, usize)> = (0..path.len())
+        .filter(|&k| {
+            let before = path[(k + path.len() - 1) % path.len()];
+            let after = path[(k + 1) % path.len()];
+            return before.0 != after.0 && before.1 != after.1;
+        })
+        .map(|k| path[k])
+        .collect();
+
+    let mut spacing = |most: i64| -> Vec<i64> {
+        let mut total = 0;
+        let mut lines = vec![];
+        for _ in 0..size {
+            lines.push(total);
+            total += generator.range(1..=most);
+        }
+        return lines;
+    };
+    let (rows, columns) = (spacing(10), spacing(10));
+    let (far_rows, far_columns) = (
+        spacing(0xfffff / size as i64),
+        spacing(0xfffff / size as i64),
+    );
+
+    let mut lines = vec![];
+
+    for (k, &(i, j)) in corners.iter().enumerate() {
+        let (next_i, next_j) = corners[(k + 1) % corners.len()];
+
+        let (direction, code, distance, far) = if next_i == i {
+            match next_j > j {
+                true => (
+                    "R",
+                    0,
+                    columns[next_j] - columns[j],
+                    far_columns[next_j] - far_columns[j],
+                ),
+                false => (
+                    "L",
+                    2,
+                    columns[j] - columns[next_j],
+                    far_columns[j] - far_columns[next_j],
+                ),
+            }
+        } else {
+            match next_i > i {
+                true => (
+                    "D",
+                    1,
+                    rows[next_i] - rows[i],
+                    far_rows[next_i] - far_rows[i],
+                ),
+                false => (
+                    "U",
+                    3,
+                    rows[i] - rows[next_i],
+                    far_rows[i] - far_rows[next_i],
+                ),
+            }
+        };
+
+        lines.push(format!("{} {} (#{:05x}{})", direction, distance, far, code));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day18_demo.txt";
+    let count = part1(&crate::load(path)).unwrap();
+    assert_eq!(62, count);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day18_demo.txt";
+    let count = part2(&crate::load(path)).unwrap();
+    assert_eq!(952408144115, count);
+}
+
+/// The size of the lagoon found by digging out each cube of the trench, then flooding the ground
+/// around it and counting what the flood does not reach
+#[cfg(test)]
+fn brute_force_lagoon(instructions: &[DiggerInstructions]) -> usize {
+    let mut trench = std::collections::HashSet::new();
+    let (mut i, mut j) = (0, 0);
+    for instruction in instructions {
+        let delta = instruction.direction.delta();
+        for _ in 0..instruction.distance {
+            i += delta.y as i32;
+            j += delta.x as i32;
+            trench.insert((i, j));
+        }
+    }
+
+    // Leave a ring of ground around the trench so the flood can go all the way round
+    let min_i = trench.iter().map(|p| p.0).min().unwrap() - 1;
+    let max_i = trench.iter().map(|p| p.0).max().unwrap() + 1;
+    let min_j = trench.iter().map(|p| p.1).min().unwrap() - 1;
+    let max_j = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+    let mut outside = std::collections::HashSet::from([(min_i, min_j)]);
+    let mut stack = vec![(min_i, min_j)];
+    while let Some((i, j)) = stack.pop() {
+        for (ni, nj) in [(i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)] {
+            let within = (min_i..=max_i).contains(&ni) && (min_j..=max_j).contains(&nj);
+            if within && !trench.contains(&(ni, nj)) && outside.insert((ni, nj)) {
+                stack.push((ni, nj));
+            }
+        }
+    }
+
+    let area = (max_i - min_i + 1) * (max_j - min_j + 1);
+    return area as usize - outside.len();
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_lagoon_matches_brute_force(seed in proptest::num::u64::ANY, size in 3..=8usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let mut day = Day18::parse(&generate(&mut generator)).unwrap();
+
+        let expected = brute_force_lagoon(&day.instructions);
+        proptest::prop_assert_eq!(part1(&day).unwrap() as usize, expected);
+
+        // Have part 2 follow the small plan too, so the shoelace formula can be checked against it
+        for instruction in day.instructions.iter_mut() {
+            instruction.colour_direction = instruction.direction;
+            instruction.colour_distance = instruction.distance as i64;
+        }
+        proptest::prop_assert_eq!(part2(&day).unwrap() as usize, expected);
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::{HashMap, VecDeque};
+
+use log::{debug, trace};
+
+use crate::{
+    generate::Generator,
+    input::blocks,
+    parse::{
+        delimited, identifier, integer, key_value, map, opt, pair, parse_line, separated, tag,
+        take_while, terminated, try_map, Parser,
+    },
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day19 {
+    workflows: HashMap<String, Vec<Condition>>,
+    items: Vec<Item>,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self> {
+        return parse_input(input);
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 19,
+    solution: Day19,
+    input: "data/day19.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day19_demo.txt",
+            expected: Answer::Integer(19114),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day19_demo.txt",
+            expected: Answer::Integer(167409079868000),
+        },
+    ],
+}
+
+fn part1(day: &Day19) -> Result<i64> {
+    let workflows = &day.workflows;
+    let items = &day.items;
+    let mut sum = 0;
+    for item in items {
+        if evaluate_rec(&item, &workflows, "in".to_string())? {
+            debug!("Accepted {:?}", item);
+            sum += item.total();
+        } else {
+            debug!("Rejected {:?}", item);
+        }
+    }
+
+    return Ok(sum);
+}
+
+fn get_range_combination(ranges: &HashMap<char, [i64; 2]>) -> i64 {
+    let mut total = 1;
+    for (_, val) in ranges.iter() {
+        total *= val[1] - val[0] + 1;
+    }
+
+    return total;
+}
+
+fn part2(day: &Day19) -> Result<i64> {
+    let workflows = &day.workflows;
+
+    let part_ranges: HashMap<char, [i64; 2]> = vec![
+        ('x', [1, 4000]),
+        ('m', [1, 4000]),
+        ('a', [1, 4000]),
+        ('s', [1, 4000]),
+    ]
+    .into_iter()
+    .collect();
+    let mut stack = vec![(part_ranges, "in".to_string())];
+    let mut ttl = 0;
+    while let Some((mut ranges, pipeline)) = stack.pop() {
+        if pipeline == "A" {
+            ttl += get_range_combination(&ranges);
+            continue;
+        } else if pipeline == "R" {
+            continue;
+        }
+
+        let conditions = match workflows.get(&pipeline) {
+            Some(x) => x,
+            None => return Err(unknown_workflow(&pipeline)),
+        };
+        for condition in conditions {
+            trace!("Assessing condition {:?}", condition);
+            if condition.dest == "A".to_string() && condition.property == Property::Any {
+                ttl += get_range_combination(&ranges);
+                continue;
+            } else if condition.dest == "R".to_string() && condition.property == Property::Any {
+                continue;
+            }
+
+            if condition.property == Property::Any {
+                stack.push((ranges, condition.dest.clone()));
+                break;
+            }
+
+            let a = condition.property.to_char();
+            let val = condition.value;
+            let vals = ranges.remove(&a).unwrap().clone();
+
+            let low = vals[0];
+            let high = vals[1];
+            let mut new_ranges = ranges.clone();
+
+            if condition.gt {
+                new_ranges.insert(a, [val + 1, high]);
+                ranges.insert(a, [low, val]);
+            } else {
+                new_ranges.insert(a, [low, val - 1]);
+                ranges.insert(a, [val, high]);
+            }
+            stack.push((new_ranges, condition.dest.clone()));
+        }
+    }
+
+    return Ok(ttl);
+}
+
+fn evaluate_rec(
+    item: &Item,
+    workflows: &HashMap<String, Vec<Condition>>,
+    workflow: String,
+) -> Result<bool> {
+    // returns true if accepted
+    trace!("Evaluating item {:?}", item);
+    trace!("I'm on workflow {}", workflow);
+    let conditions = match workflows.get(&workflow) {
+        Some(x) => x,
+        None => return Err(unknown_workflow(&workflow)),
+    };
+
+    let mut dest = None;
+
+    for condition in conditions {
+        trace!("Looking at condition {:?}", condition);
+        let meets = condition.meets_condition(item);
+
+        trace!("This item meets this condition {}", meets);
+
+        if meets && condition.dest == "A" {
+            return Ok(true);
+        }
+
+        if meets && condition.dest == "R" {
+            return Ok(false);
+        }
+
+        if !meets {
+            continue;
+        }
+
+        if meets {
+            dest = Some(condition.dest.clone());
+            break;
+        }
+    }
+
+    return match dest {
+        Some(dest) => evaluate_rec(item, workflows, dest),
+        None => Err(Error::unsolvable(format!(
+            "no rule in workflow `{}` matches {:?}",
+            workflow, item
+        ))),
+    };
+}
+
+fn unknown_workflow(name: &str) -> Error {
+    return Error::unsolvable(format!("there is no workflow `{}`", name));
+}
+
+fn parse_input(input: &str) -> Result<Day19> {
+    let mut workflows: HashMap<String, Vec<Condition>> = HashMap::new();
+    let mut items = vec![];
+
+    for (b, block) in blocks(input).enumerate() {
+        for (i, line) in block.numbered() {
+            if b == 0 {
+                let (name, conditions) = parse_line(i, line, workflow())?;
+                workflows.insert(name.to_string(), conditions);
+            } else {
+                items.push(parse_line(i, line, item())?);
+            }
+        }
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(Error::parse(0, 0, "there is no `in` workflow"));
+    }
+
+    trace!("Here's the items {:?}", items);
+    trace!("Here's the workflows {:?}", workflows);
+
+    return Ok(Day19 { workflows, items });
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn workflow<'a>() -> impl Parser<'a, (&'a str, Vec<Condition>)> {
+    return pair(
+        identifier(),
+        delimited(tag("{"), separated(condition(), tag(",")), tag("}")),
+    );
+}
+
+/// `a<2006:qkq`, or just `rfg` for the rule that always applies
+fn condition<'a>() -> impl Parser<'a, Condition> {
+    let gt = try_map(
+        take_while("`<` or `>`", |c| c == '<' || c == '>'),
+        |gt| match gt {
+            ">" => Ok(true),
+            "<" => Ok(false),
+            _ => Err(format!("unknown comparison `{}`", gt)),
+        },
+    );
+    let comparison = terminated(pair(property(), pair(gt, integer())), tag(":"));
+
+    return map(
+        pair(opt(comparison), identifier()),
+        |(comparison, dest)| match comparison {
+            Some((property, (gt, value))) => Condition {
+                property,
+                gt,
+                value,
+                dest: dest.to_string(),
+            },
+            None => Condition {
+                property: Property::Any,
+                gt: true,
+                value: i64::MIN,
+                dest: dest.to_string(),
+            },
+        },
+    );
+}
+
+/// `{x=787,m=2655,a=1222,s=2876}`
+fn item<'a>() -> impl Parser<'a, Item> {
+    let ratings = delimited(
+        tag("{"),
+        separated(key_value(property(), tag("="), integer()), tag(",")),
+        tag("}"),
+    );
+
+    return try_map(ratings, |ratings| {
+        let order = [
+            Property::ExtremelyCool,
+            Property::Musical,
+            Property::Aerodynamic,
+            Property::Shiny,
+        ];
+
+        let in_order =
+            ratings.len() == order.len() && ratings.iter().zip(&order).all(|((p, _), q)| p == q);
+        if !in_order {
+            return Err("expected `{x=..,m=..,a=..,s=..}`".to_string());
+        }
+
+        return Ok(Item {
+            x: ratings[0].1,
+            m: ratings[1].1,
+            a: ratings[2].1,
+            s: ratings[3].1,
+        });
+    });
+}
+
+fn property<'a>() -> impl Parser<'a, Property> {
+    return try_map(take_while("a rating", |c| c.is_ascii_lowercase()), |name| {
+        Property::from_str(name).ok_or_else(|| format!("unknown rating `{}`", name))
+    });
+}
+
+#[derive(Debug)]
+struct Condition {
+    pub property: Property,
+    pub gt: bool,
+    pub value: i64,
+    pub dest: String,
+}
+
+impl Condition {
+    pub fn meets_condition(&self, item: &Item) -> bool {
+        let comp = match self.gt {
+            true => greater_than,
+            false => less_than,
+        };
+
+        match self.property {
+            Property::ExtremelyCool => comp(item.x, self.value),
+            Property::Musical => comp(item.m, self.value),
+            Property::Aerodynamic => comp(item.a, self.value),
+            Property::Shiny => comp(item.s, self.value),
+            Property::Any => return true,
+        }
+    }
+}
+
+fn greater_than(a: i64, b: i64) -> bool {
+    return a > b;
+}
+
+fn less_than(a: i64, b: i64) -> bool {
+    return a < b;
+}
+
+#[derive(Debug, PartialEq)]
+enum Property {
+    ExtremelyCool,
+    Musical,
+    Aerodynamic,
+    Shiny,
+    Any,
+}
+
+impl Property {
+    pub fn to_char(&self) -> char {
+        return match self {
+            Property::ExtremelyCool => 'x',
+            Property::Musical => 'm',
+            Property::Aerodynamic => 'a',
+            Property::Shiny => 's',
+            Property::Any => panic!("Unreachable code"),
+        };
+    }
+}
+
+impl Property {
+    fn from_str(value: &str) -> Option<Self> {
+        if value == "x" {
+            return Some(Self::ExtremelyCool);
+        } else if value == "m" {
+            return Some(Self::Musical);
+        } else if value == "a" {
+            return Some(Self::Aerodynamic);
+        } else if value == "s" {
+            return Some(Self::Shiny);
+        } else {
+            return None;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Item {
+    pub x: i64,
+    pub m: i64,
+    pub a: i64,
+    pub s: i64,
+}
+
+impl Item {
+    pub fn total(&self) -> i64 {
+        return self.x + self.m + self.a + self.s;
+    }
+}
+
+/// About `size` workflows branching out from `in`, and half as many parts
+fn generate(generator: &mut Generator) -> String {
+    const PROPERTIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+    let count = generator.size_or(500).max(1);
+    let mut names = generator.names(count, 3, &["in"]).into_iter();
+
+    // Each workflow is only reached by parts in its ranges, and its conditions split those
+    // ranges, so no condition is decided before it is checked. Workflows keep branching until
+    // there are `size` of them
+    let mut queue = VecDeque::from([("in".to_string(), [[1, 4000]; 4])]);
+    let mut made = 1;
+    let mut workflows = vec![];
+
+    while let Some((name, mut ranges)) = queue.pop_front() {
+        let mut rules = vec![];
+
+        let mut destination = |generator: &mut Generator, ranges: [[i64; 2]; 4]| -> String {
+            if made < count && (queue.is_empty() || generator.chance(0.6)) {
+                if let Some(child) = names.next() {
+                    made += 1;
+                    queue.push_back((child.clone(), ranges));
+                    return child;
+                }
+            }
+            return generator.choose(&["A", "R"]).to_string();
+        };
+
+        for _ in 0..generator.range(1..=4) {
+            let p = generator.range(0..4);
+            let [low, high] = ranges[p];
+            if high - low < 2 {
+                continue;
+            }
+
+            let value = generator.range(low + 1..high);
+            let mut taken = ranges;
+
+            let rule = if generator.chance(0.5) {
+                taken[p] = [low, value - 1];
+                ranges[p] = [value, high];
+                format!("{}<{}", PROPERTIES[p], value)
+            } else {
+                taken[p] = [value + 1, high];
+                ranges[p] = [low, value];
+                format!("{}>{}", PROPERTIES[p], value)
+            };
+
+            rules.push(format!("{}:{}", rule, destination(generator, taken)));
+        }
+
+        rules.push(destination(generator, ranges));
+        workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+
+    generator.shuffle(&mut workflows);
+
+    let mut parts = vec![];
+    for _ in 0..(count / 2).max(1) {
+        let ratings: Vec<String> = PROPERTIES
+            .iter()
+            .map(|p| format!("{}={}", p, generator.range(1..=4000)))
+            .collect();
+        parts.push(format!("{{{}}}", ratings.join(",")));
+    }
+
+    return format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"));
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day19_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(19114, sum);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day19_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(167409079868000, sum);
+}
+
+/// The combinations of ratings that are accepted, found by evaluating one part from each box of
+/// ratings that no rule tells apart, and counting the whole box if it is accepted
+#[cfg(test)]
+fn brute_force_combinations(workflows: &HashMap<String, Vec<Condition>>) -> i64 {
+    let properties = [
+        Property::ExtremelyCool,
+        Property::Musical,
+        Property::Aerodynamic,
+        Property::Shiny,
+    ];
+
+    // Every rating where some rule's answer changes, for each property
+    let starts: Vec<Vec<i64>> = properties
+        .iter()
+        .map(|property| {
+            let mut starts = vec![1, 4001];
+            for condition in workflows.values().flatten() {
+                if condition.property == *property {
+                    starts.push(match condition.gt {
+                        true => condition.value + 1,
+                        false => condition.value,
+                    });
+                }
+            }
+            starts.retain(|start| (1..=4001).contains(start));
+            starts.sort();
+            starts.dedup();
+            return starts;
+        })
+        .collect();
+
+    let mut total = 0;
+
+    for x in starts[0].windows(2) {
+        for m in starts[1].windows(2) {
+            for a in starts[2].windows(2) {
+                for s in starts[3].windows(2) {
+                    let item = Item {
+                        x: x[0],
+                        m: m[0],
+                        a: a[0],
+                        s: s[0],
+                    };
+
+                    if evaluate_rec(&item, workflows, "in".to_string()).unwrap() {
+                        total += (x[1] - x[0]) * (m[1] - m[0]) * (a[1] - a[0]) * (s[1] - s[0]);
+                    }
+                }
+            }
+        }
+    }
+
+    return total;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, size in 1..=8usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let day = Day19::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_combinations(&day.workflows));
+    }
+}
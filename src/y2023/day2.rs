@@ -0,0 +1,189 @@
+use crate::{
+    generate::Generator,
+    parse::{
+        delimited, identifier, integer, map, pair, parse_lines, separated, spaces, tag, terminated,
+        try_map, Parser,
+    },
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+const NUM_RED: i32 = 12;
+const NUM_GREEN: i32 = 13;
+const NUM_BLUE: i32 = 14;
+
+const BLUE: &str = "blue";
+const RED: &str = "red";
+const GREEN: &str = "green";
+
+pub struct Day2 {
+    games: Vec<Game>,
+}
+
+impl Solution for Day2 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            games: parse_lines(input, game())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 2,
+    solution: Day2,
+    input: "data/day2.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day2_demo.txt",
+            expected: Answer::Integer(8),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day2_demo.txt",
+            expected: Answer::Integer(2286),
+        },
+    ],
+}
+
+fn part1(day: &Day2) -> Result<i32> {
+    let mut sum = 0;
+
+    for game in &day.games {
+        let possible = game.rounds.iter().flatten().all(|(count, colour)| {
+            let limit = match colour {
+                Colour::Red => NUM_RED,
+                Colour::Green => NUM_GREEN,
+                Colour::Blue => NUM_BLUE,
+            };
+
+            return *count <= limit;
+        });
+
+        if possible {
+            sum += game.number;
+        }
+    }
+
+    return Ok(sum);
+}
+
+fn part2(day: &Day2) -> Result<i32> {
+    let mut sum = 0;
+
+    for game in &day.games {
+        let mut reds = 0;
+        let mut greens = 0;
+        let mut blues = 0;
+
+        for (count, colour) in game.rounds.iter().flatten() {
+            let most = match colour {
+                Colour::Red => &mut reds,
+                Colour::Green => &mut greens,
+                Colour::Blue => &mut blues,
+            };
+
+            *most = (*most).max(*count);
+        }
+
+        sum += reds * blues * greens;
+    }
+
+    return Ok(sum);
+}
+
+struct Game {
+    number: i32,
+    /// The cubes shown in each round, as counts of a colour
+    rounds: Vec<Vec<(i32, Colour)>>,
+}
+
+enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green`
+fn game<'a>() -> impl Parser<'a, Game> {
+    let colour = try_map(identifier(), |name| match name {
+        RED => Ok(Colour::Red),
+        GREEN => Ok(Colour::Green),
+        BLUE => Ok(Colour::Blue),
+        _ => Err(format!("unknown colour `{}`", name)),
+    });
+    let cubes = pair(terminated(integer(), spaces()), colour);
+    let round = separated(cubes, tag(", "));
+
+    return map(
+        pair(
+            delimited(tag("Game "), integer(), tag(": ")),
+            separated(round, tag("; ")),
+        ),
+        |(number, rounds)| Game { number, rounds },
+    );
+}
+
+/// `size` games of up to six rounds each
+fn generate(generator: &mut Generator) -> String {
+    let mut lines = vec![];
+
+    for number in 1..=generator.size_or(100) {
+        let mut rounds = vec![];
+
+        for _ in 0..generator.range(1..=6) {
+            let mut colours = [RED, GREEN, BLUE];
+            generator.shuffle(&mut colours);
+
+            let count = generator.range(1..=3);
+            let cubes: Vec<String> = colours[..count]
+                .iter()
+                .map(|colour| format!("{} {}", generator.range(1..=20), colour))
+                .collect();
+
+            rounds.push(cubes.join(", "));
+        }
+
+        lines.push(format!("Game {}: {}", number, rounds.join("; ")));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let file_path = "data_demo/day2_demo.txt";
+    let sum = part1(&crate::load(file_path)).unwrap();
+    eprintln!("Day 2 Part 1 Test Sum {}", sum);
+    assert_eq!(sum, 8);
+}
+
+#[test]
+fn test_part2() {
+    let file_path = "data_demo/day2_demo.txt";
+    let sum = part2(&crate::load(file_path)).unwrap();
+    eprintln!("Day 2 Part 2 Test Sum {}", sum);
+    assert_eq!(sum, 2286);
+}
+
+#[test]
+fn test_bad_count() {
+    let error = Day2::parse("Game 1: x red").err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "line 1, column 9: expected a number, found `x`"
+    );
+}
@@ -0,0 +1,538 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use crate::{
+    animate::Frames,
+    generate::Generator,
+    parse::{identifier, key_value, opt, pair, parse_lines, separated, tag, take_while, try_map},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day20 {
+    modules: HashMap<String, Module>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            modules: parse_input(input)?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn animate(&self, frames: &mut dyn Frames) -> Result<bool> {
+        animate(self, frames);
+        return Ok(true);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 20,
+    solution: Day20,
+    input: "data/day20.txt",
+    demos: &[Demo {
+        part: 1,
+        input: "data_demo/day20_demo.txt",
+        expected: Answer::Integer(32000000),
+    }],
+}
+
+fn part1(day: &Day20) -> Result<i64> {
+    let mut map = day.modules.clone();
+    let mut low_pulses = 0;
+    let mut high_pulses = 0;
+
+    for _ in 0..1000 {
+        press_button(&mut map, |message| match message.pulse {
+            Pulse::Low => low_pulses += 1,
+            Pulse::High => high_pulses += 1,
+        });
+    }
+
+    return Ok(high_pulses * low_pulses);
+}
+
+/// Presses the button once, passing every pulse sent, the button's own included, to `on_pulse`
+fn press_button(map: &mut HashMap<String, Module>, mut on_pulse: impl FnMut(&Message)) {
+    let mut stack: VecDeque<Message> = VecDeque::new();
+
+    let button = Message {
+        source: "button".to_string(),
+        destination: "broadcaster".to_string(),
+        pulse: Pulse::Low,
+    };
+    on_pulse(&button);
+    stack.push_back(button);
+
+    while let Some(message) = stack.pop_front() {
+        let module = match map.get_mut(&message.destination) {
+            Some(x) => x,
+            None => continue, // There are some "loose" modules that only act as sinks
+        };
+
+        let (destinations, pulse) = match module {
+            // We can kind of ignore pulses here because we know it's going to happen on low
+            Module::Broadcast(b) => (&b.destinations, Pulse::Low),
+            Module::FlipFlop(f) => {
+                if message.pulse == Pulse::High {
+                    continue;
+                }
+
+                f.on = !f.on;
+
+                match f.on {
+                    true => (&f.destinations, Pulse::High),
+                    false => (&f.destinations, Pulse::Low),
+                }
+            }
+            Module::Conjuction(c) => {
+                let a = c.last_pulse.get_mut(&message.source).unwrap();
+                *a = message.pulse.clone();
+
+                match c.all_high() {
+                    true => (&c.destinations, Pulse::Low),
+                    false => (&c.destinations, Pulse::High),
+                }
+            }
+        };
+
+        for dest in destinations {
+            let sent = Message {
+                source: message.destination.clone(),
+                destination: dest.clone(),
+                pulse: pulse.clone(),
+            };
+            on_pulse(&sent);
+            stack.push_back(sent);
+        }
+    }
+}
+
+/// Every module's state after each of part 1's button presses
+fn animate(day: &Day20, frames: &mut dyn Frames) {
+    let mut map = day.modules.clone();
+    let mut names: Vec<&String> = day.modules.keys().collect();
+    names.sort();
+
+    let (mut total_low, mut total_high) = (0, 0);
+
+    for press in 1..=1000 {
+        let (mut low, mut high) = (0, 0);
+        press_button(&mut map, |message| match message.pulse {
+            Pulse::Low => low += 1,
+            Pulse::High => high += 1,
+        });
+        total_low += low;
+        total_high += high;
+
+        let mut text = vec![];
+        for name in &names {
+            let line = match &map[*name] {
+                Module::Broadcast(b) => format!("{} -> {}", b.name, b.destinations.join(", ")),
+                Module::FlipFlop(f) => format!(
+                    "%{} {} -> {}",
+                    f.name,
+                    if f.on { "on" } else { "off" },
+                    f.destinations.join(", ")
+                ),
+                Module::Conjuction(c) => {
+                    let mut inputs: Vec<String> = c
+                        .last_pulse
+                        .iter()
+                        .map(|(input, pulse)| format!("{}={}", input, pulse))
+                        .collect();
+                    inputs.sort();
+                    format!(
+                        "&{} [{}] -> {}",
+                        c.name,
+                        inputs.join(" "),
+                        c.destinations.join(", ")
+                    )
+                }
+            };
+            text.push(line);
+        }
+
+        frames.push(
+            format!(
+                "press {}: {} low and {} high pulses, {} low and {} high in all",
+                press, low, high, total_low, total_high
+            ),
+            text.join("\n"),
+        );
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Module>> {
+    // `%a -> b, c`, where the name is prefixed by `%` for a flip-flop and `&` for a conjunction
+    let header = try_map(
+        pair(
+            opt(take_while("a module type", |c| c == '%' || c == '&')),
+            identifier(),
+        ),
+        |(kind, name)| match (kind, name) {
+            (None, "broadcaster") | (Some("%" | "&"), _) => Ok((kind, name)),
+            _ => Err(format!(
+                "unknown module type `{}{}`",
+                kind.unwrap_or(""),
+                name
+            )),
+        },
+    );
+    let module = key_value(header, tag(" -> "), separated(identifier(), tag(", ")));
+
+    let mut map = HashMap::new();
+
+    for ((kind, name), destinations) in parse_lines(input, module)? {
+        let destinations = destinations.iter().map(|d| d.to_string()).collect();
+
+        let module = match kind {
+            None => Module::Broadcast(Broadcast {
+                name: name.to_string(),
+                destinations,
+            }),
+            Some("%") => Module::FlipFlop(FlipFlop {
+                name: name.to_string(),
+                on: false,
+                destinations,
+            }),
+            _ => Module::Conjuction(Conjunction {
+                name: name.to_string(),
+                last_pulse: HashMap::new(),
+                destinations,
+            }),
+        };
+
+        map.insert(name.to_string(), module);
+    }
+
+    if !map.contains_key("broadcaster") {
+        return Err(Error::parse(0, 0, "there is no `broadcaster` module"));
+    }
+
+    // Initialise the conjugation modules
+    for (name, module) in map.clone().iter() {
+        match module {
+            Module::Broadcast(b) => {
+                for dest in &b.destinations {
+                    let m = match map.get_mut(dest) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+
+                    match m {
+                        Module::Broadcast(_) => {}
+                        Module::FlipFlop(_) => {}
+                        Module::Conjuction(c) => {
+                            c.last_pulse.insert(name.clone(), Pulse::Low);
+                        }
+                    }
+                }
+            }
+            Module::FlipFlop(f) => {
+                for dest in &f.destinations {
+                    let m = match map.get_mut(dest) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+
+                    match m {
+                        Module::Broadcast(_) => {}
+                        Module::FlipFlop(_) => {}
+                        Module::Conjuction(c) => {
+                            c.last_pulse.insert(name.clone(), Pulse::Low);
+                        }
+                    }
+                }
+            }
+            Module::Conjuction(c) => {
+                for dest in &c.destinations {
+                    let m = match map.get_mut(dest) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+
+                    match m {
+                        Module::Broadcast(_) => {}
+                        Module::FlipFlop(_) => {}
+                        Module::Conjuction(c) => {
+                            c.last_pulse.insert(name.clone(), Pulse::Low);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return Ok(map);
+}
+
+fn part2(day: &Day20) -> Result<i64> {
+    let mut map = day.modules.clone();
+
+    let mut running = true;
+    let mut button_press = 0;
+
+    let mut button_press_map = HashMap::new();
+
+    // The cycle lengths of these four conjunctions are specific to one input
+    let conjunctions = ["lh", "fk", "ff", "mm"];
+
+    for conj in conjunctions {
+        match map.get(conj) {
+            Some(Module::Conjuction(_)) => {}
+            _ => {
+                return Err(Error::unsolvable(format!(
+                    "this input has no conjunction module `{}`",
+                    conj
+                )))
+            }
+        }
+    }
+
+    while running {
+        button_press += 1;
+
+        // Note the first press on which each conjunction sends a high pulse
+        press_button(&mut map, |message| {
+            if message.pulse == Pulse::High
+                && conjunctions.contains(&message.source.as_str())
+                && !button_press_map.contains_key(&message.source)
+            {
+                button_press_map.insert(message.source.clone(), button_press);
+            }
+        });
+
+        if button_press_map.len() == conjunctions.len() {
+            running = false;
+        }
+    }
+
+    // The cycles are usually coprime, but need not be
+    let mut presses: i64 = 1;
+    for &cycle in button_press_map.values() {
+        let (mut a, mut b) = (presses, cycle);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        presses = match (presses / a).checked_mul(cycle) {
+            Some(x) => x,
+            None => return Err(Error::unsolvable("rx needs too many presses to count")),
+        };
+    }
+
+    return Ok(presses);
+}
+
+#[derive(Clone, Debug)]
+enum Module {
+    Broadcast(Broadcast),
+    FlipFlop(FlipFlop),
+    Conjuction(Conjunction),
+}
+
+#[derive(Clone, Debug)]
+struct Broadcast {
+    pub name: String,
+    pub destinations: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+struct FlipFlop {
+    pub name: String,
+    pub on: bool,
+    pub destinations: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+struct Conjunction {
+    pub name: String,
+    pub last_pulse: HashMap<String, Pulse>,
+    pub destinations: Vec<String>,
+}
+
+impl Conjunction {
+    pub fn all_high(&self) -> bool {
+        for (_, input) in self.last_pulse.iter() {
+            if *input == Pulse::Low {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+enum Pulse {
+    High,
+    Low,
+}
+
+impl Display for Pulse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pulse::High => write!(f, "high"),
+            Pulse::Low => write!(f, "low"),
+        }
+    }
+}
+
+struct Message {
+    pub source: String,
+    pub destination: String,
+    pub pulse: Pulse,
+}
+
+/// Four counters of `size` flip-flops each, from 6 to 15, wired to `rx` like the puzzle input. Each
+/// counter resets after a different prime number of presses
+fn generate(generator: &mut Generator) -> String {
+    // Four primes of up to 15 bits multiply to less than 2^60, so the answer fits an i64
+    let bits = generator.size_or(12).clamp(6, 15);
+
+    let primes: Vec<u64> = ((1 << (bits - 1)) + 1..1 << bits)
+        .step_by(2)
+        .filter(|&n: &u64| {
+            (3..)
+                .step_by(2)
+                .take_while(|d| d * d <= n)
+                .all(|d| n % d != 0)
+        })
+        .take(64)
+        .collect();
+    let mut primes = primes;
+    generator.shuffle(&mut primes);
+
+    return counters(generator, bits, &primes[..4]);
+}
+
+/// Four counters of `bits` flip-flops wired to `rx` like the puzzle input, each resetting after
+/// its own odd number of `presses`
+fn counters(generator: &mut Generator, bits: usize, presses: &[u64]) -> String {
+    const INVERTERS: [&str; 4] = ["lh", "fk", "ff", "mm"];
+
+    let mut taken = INVERTERS.to_vec();
+    taken.extend(["zh", "rx"]);
+    let mut names = generator.names(4 * (bits + 1), 2, &taken).into_iter();
+
+    let mut lines = vec![];
+    let mut starts = vec![];
+
+    for (k, inverter) in INVERTERS.iter().enumerate() {
+        let flip_flops: Vec<String> = names.by_ref().take(bits).collect();
+        let hub = names.next().unwrap();
+        let presses = presses[k];
+
+        // A set bit sends its count on to the hub, which resets the counter once they all are
+        let mut resets = vec![flip_flops[0].clone()];
+
+        for (bit, name) in flip_flops.iter().enumerate() {
+            let mut destinations = vec![];
+            if let Some(next) = flip_flops.get(bit + 1) {
+                destinations.push(next.clone());
+            }
+
+            if presses >> bit & 1 == 1 {
+                destinations.push(hub.clone());
+            } else {
+                resets.push(name.clone());
+            }
+
+            generator.shuffle(&mut destinations);
+            lines.push(format!("%{} -> {}", name, destinations.join(", ")));
+        }
+
+        resets.push(inverter.to_string());
+        generator.shuffle(&mut resets);
+        lines.push(format!("&{} -> {}", hub, resets.join(", ")));
+        lines.push(format!("&{} -> zh", inverter));
+
+        starts.push(flip_flops[0].clone());
+    }
+
+    lines.push("&zh -> rx".to_string());
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    generator.shuffle(&mut lines);
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day20_demo.txt";
+    let mult = part1(&crate::load(path)).unwrap();
+    assert_eq!(mult, 32000000);
+}
+
+#[test]
+fn test_generate() {
+    let input = Day20::generate(&mut Generator::new(3, Some(6)));
+    let mut presses = part2(&Day20::parse(&input).unwrap()).unwrap();
+
+    // Four different primes of six bits, one per counter
+    let mut primes = vec![];
+    for p in 2.. {
+        while presses % p == 0 {
+            primes.push(p);
+            presses /= p;
+        }
+        if presses == 1 {
+            break;
+        }
+    }
+
+    assert_eq!(primes.len(), 4);
+    primes.dedup();
+    assert_eq!(primes.len(), 4);
+    assert!(primes.iter().all(|p| (32..64).contains(p)));
+}
+
+/// The presses until `rx` is sent a low pulse, found by pressing the button until it is
+#[cfg(test)]
+fn brute_force_presses(day: &Day20) -> i64 {
+    let mut map = day.modules.clone();
+    let mut presses = 0;
+    let mut low_to_rx = false;
+
+    while !low_to_rx {
+        presses += 1;
+        press_button(&mut map, |message| {
+            low_to_rx |= message.destination == "rx" && message.pulse == Pulse::Low;
+        });
+    }
+
+    return presses;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(32))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, bits in 3..=4usize) {
+        // Odd cycles that need not be prime, so the counters can share factors
+        let mut generator = Generator::new(seed, None);
+        let presses: Vec<u64> = (0..4)
+            .map(|_| generator.range(1 << (bits - 2)..1 << (bits - 1)) * 2 + 1)
+            .collect();
+        let day = Day20::parse(&counters(&mut generator, bits, &presses)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_presses(&day));
+    }
+}
@@ -0,0 +1,455 @@
+use log::{debug, trace};
+
+use crate::{
+    animate::Frames,
+    generate::Generator,
+    grid::Grid,
+    lines,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day21 {
+    grid: Grid<Garden>,
+    start: (usize, usize),
+}
+
+impl Solution for Day21 {
+    fn parse(input: &str) -> Result<Self> {
+        return parse_input(&lines(input));
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self, 64)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn part1_demo(&self) -> Result<Answer> {
+        return Ok(part1(self, 6)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)));
+    }
+
+    fn animate(&self, frames: &mut dyn Frames) -> Result<bool> {
+        animate(self, frames);
+        return Ok(true);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 21,
+    solution: Day21,
+    input: "data/day21.txt",
+    demos: &[Demo {
+        part: 1,
+        input: "data_demo/day21_demo.txt",
+        expected: Answer::Integer(16),
+    }],
+}
+
+fn part1(day: &Day21, steps: usize) -> Result<usize> {
+    return Ok(reachable(day, steps, |_, _| {}).len());
+}
+
+/// The plots the elf can be on after exactly `steps` steps. `on_step` is given each step's plots
+fn reachable(
+    day: &Day21,
+    steps: usize,
+    mut on_step: impl FnMut(usize, &[(usize, usize)]),
+) -> Vec<(usize, usize)> {
+    let grid = &day.grid;
+    let (start_i, start_j) = day.start;
+
+    let height = grid.height();
+    let width = grid.width();
+
+    let mut stack = vec![(start_i, start_j)];
+
+    on_step(0, &stack);
+
+    for step in 1..=steps {
+        let mut tmp_stack = vec![];
+
+        while let Some((i, j)) = stack.pop() {
+            // NOrth
+            if i > 0 {
+                let new_i = i - 1;
+
+                if grid[(new_i, j)] == Garden::Plot {
+                    if !tmp_stack.contains(&(new_i, j)) {
+                        tmp_stack.push((new_i, j));
+                    }
+                }
+            }
+
+            // East
+
+            if j < width - 1 {
+                let new_j = j + 1;
+
+                if grid[(i, new_j)] == Garden::Plot {
+                    if !tmp_stack.contains(&(i, new_j)) {
+                        tmp_stack.push((i, new_j));
+                    }
+                }
+            }
+
+            // South
+            if i < height - 1 {
+                let new_i = i + 1;
+
+                if grid[(new_i, j)] == Garden::Plot {
+                    if !tmp_stack.contains(&(new_i, j)) {
+                        tmp_stack.push((new_i, j));
+                    }
+                }
+            }
+
+            // West
+
+            if j > 0 {
+                let new_j = j - 1;
+
+                if grid[(i, new_j)] == Garden::Plot {
+                    if !tmp_stack.contains(&(i, new_j)) {
+                        tmp_stack.push((i, new_j));
+                    }
+                }
+            }
+        }
+
+        stack = tmp_stack;
+        on_step(step, &stack);
+    }
+
+    return stack;
+}
+
+/// The plots the elf can be on, as `O`, after each of part 1's 64 steps
+fn animate(day: &Day21, frames: &mut dyn Frames) {
+    reachable(day, 64, |step, plots| {
+        let mut text = day.grid.map(|garden| match garden {
+            Garden::Plot => '.',
+            Garden::Rocks => '#',
+        });
+        for &plot in plots {
+            text[plot] = 'O';
+        }
+
+        frames.push(
+            format!("step {}, {} plots reachable", step, plots.len()),
+            text.to_string(),
+        );
+    });
+}
+
+/// The rocks in grey and the plots reachable in part 1's 64 steps in green, from the start in red
+fn render(day: &Day21) -> Picture {
+    let mut picture = Picture::from_grid(&day.grid, |garden| match garden {
+        Garden::Plot => Colour::BLACK,
+        Garden::Rocks => Colour::GREY,
+    });
+    picture.highlight(reachable(day, 64, |_, _| {}), Colour::GREEN);
+    picture.set(day.start.0, day.start.1, Colour::RED);
+
+    return picture;
+}
+
+fn part2(day: &Day21) -> Result<usize> {
+    // The quadratic fit below relies on the real input's 131x131 grid with S in the middle
+    if day.grid.height() != 131 || day.grid.width() != 131 || day.start != (65, 65) {
+        return Err(Error::unsolvable(
+            "part 2 needs a 131x131 grid with the start in the centre",
+        ));
+    }
+
+    return extrapolate(day, 26_501_365);
+}
+
+/// The plots reachable after `steps` steps on the infinitely repeated grid, fitting a quadratic
+/// to the counts after walking to the edge of the grid and then one and two grids further
+fn extrapolate(day: &Day21, steps: usize) -> Result<usize> {
+    let grid = &day.grid;
+    let (start_i, start_j) = day.start;
+
+    let size = grid.height();
+    let half = size / 2;
+
+    // The quadratic fit relies on a square grid with S in the middle, and on `steps` ending on
+    // the edge of a grid
+    if grid.width() != size || size.is_multiple_of(2) || day.start != (half, half) {
+        return Err(Error::unsolvable(
+            "extrapolating needs a square grid with the start in the centre",
+        ));
+    }
+    if steps < half || !(steps - half).is_multiple_of(size) {
+        return Err(Error::unsolvable(format!(
+            "extrapolating needs {} steps to end on the edge of a {}x{} grid",
+            steps, size, size
+        )));
+    }
+
+    let [one, two, three] =
+        simulate_all(grid, start_i, start_j, [half, half + size, half + 2 * size]);
+
+    debug!("One: {}", one);
+    debug!("Two: {}", two);
+    debug!("Three: {}", three);
+
+    let a = (three - (2 * two) + one) / 2;
+    let b = two - one - a;
+    let c = one;
+
+    let n = ((steps - half) / size) as i64;
+
+    debug!("a: {}, b: {}, c: {}, n: {}", a, b, c, n);
+
+    let result = (a * (n * n)) + (b * n) + c;
+
+    return Ok(result as usize);
+}
+
+/// Counts the plots reachable after each number of steps, each on a blocking task of a tokio
+/// runtime
+#[cfg(feature = "async")]
+fn simulate_all(
+    grid: &Grid<Garden>,
+    start_i: usize,
+    start_j: usize,
+    steps: [usize; 3],
+) -> [i64; 3] {
+    // Blocking tasks must own what they use
+    let grid = std::sync::Arc::new(grid.clone());
+
+    return tokio::task::block_in_place(|| {
+        let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+        runtime.block_on(async {
+            let [one, two, three] = steps.map(|steps| {
+                let grid = grid.clone();
+                tokio::task::spawn_blocking(move || simulate_part_2(&grid, start_i, start_j, steps))
+            });
+
+            // Spawned tasks are already running, so awaiting them in turn still overlaps them
+            [one.await, two.await, three.await]
+                .map(|count| count.expect("A simulation panicked") as i64)
+        })
+    });
+}
+
+/// Counts the plots reachable after each number of steps, on a thread each
+#[cfg(not(feature = "async"))]
+fn simulate_all(
+    grid: &Grid<Garden>,
+    start_i: usize,
+    start_j: usize,
+    steps: [usize; 3],
+) -> [i64; 3] {
+    return std::thread::scope(|scope| {
+        let handles =
+            steps.map(|steps| scope.spawn(move || simulate_part_2(grid, start_i, start_j, steps)));
+
+        handles.map(|handle| handle.join().expect("A simulation panicked") as i64)
+    });
+}
+
+fn simulate_part_2(grid: &Grid<Garden>, start_i: usize, start_j: usize, steps: usize) -> usize {
+    let mut stack = vec![(start_i as i64, start_j as i64)];
+
+    for _ in 0..steps {
+        let mut tmp_stack = vec![];
+
+        while let Some((i, j)) = stack.pop() {
+            // NOrth
+
+            let new_i = i - 1;
+
+            if *grid.get_wrapping(new_i, j) == Garden::Plot {
+                if !tmp_stack.contains(&(new_i, j)) {
+                    tmp_stack.push((new_i, j));
+                }
+            }
+
+            // East
+
+            let new_j = j + 1;
+
+            if *grid.get_wrapping(i, new_j) == Garden::Plot {
+                if !tmp_stack.contains(&(i, new_j)) {
+                    tmp_stack.push((i, new_j));
+                }
+            }
+
+            // South
+
+            let new_i = i + 1;
+
+            if *grid.get_wrapping(new_i, j) == Garden::Plot {
+                if !tmp_stack.contains(&(new_i, j)) {
+                    tmp_stack.push((new_i, j));
+                }
+            }
+
+            // West
+
+            let new_j = j - 1;
+
+            if *grid.get_wrapping(i, new_j) == Garden::Plot {
+                if !tmp_stack.contains(&(i, new_j)) {
+                    tmp_stack.push((i, new_j));
+                }
+            }
+        }
+
+        stack = tmp_stack
+    }
+
+    return stack.len();
+}
+
+fn display(grid: &Grid<Garden>, stack: &Vec<(usize, usize)>) {
+    let mut out = String::new();
+
+    for (i, line) in grid.rows().enumerate() {
+        for (j, garden) in line.iter().enumerate() {
+            if stack.contains(&(i, j)) {
+                out.push('@');
+            } else if *garden == Garden::Plot {
+                out.push('.');
+            } else if *garden == Garden::Rocks {
+                out.push('#');
+            }
+        }
+        out.push('\n');
+    }
+
+    trace!("{}", out);
+}
+
+fn parse_input(lines: &Vec<String>) -> Result<Day21> {
+    let grid = Grid::parse(lines)?;
+
+    let start = lines
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| line.chars().position(|c| c == 'S').map(|j| (i, j)));
+
+    return match start {
+        Some(start) => Ok(Day21 { grid, start }),
+        None => Err(Error::parse(0, 0, "could not find the start `S`")),
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Garden {
+    Plot,
+    Rocks,
+}
+
+impl TryFrom<char> for Garden {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<Self, char> {
+        if value == 'S' || value == '.' {
+            return Ok(Self::Plot);
+        } else if value == '#' {
+            return Ok(Self::Rocks);
+        } else {
+            return Err(value);
+        }
+    }
+}
+
+/// A `size` by `size` garden with the start in the middle, `size` rounded up to odd. The middle
+/// row and column and the edges are left clear, as in the puzzle input
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(131) | 1;
+    let middle = size / 2;
+
+    let mut garden = Grid::new(size, size, '.');
+    for i in 0..size {
+        for j in 0..size {
+            let clear =
+                i == middle || j == middle || i == 0 || j == 0 || i == size - 1 || j == size - 1;
+            if !clear && generator.chance(0.12) {
+                garden[(i, j)] = '#';
+            }
+        }
+    }
+    garden[(middle, middle)] = 'S';
+
+    return garden.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day21_demo.txt";
+    let count = part1(&crate::load(path), 6).unwrap();
+    assert_eq!(count, 16);
+}
+
+#[test]
+fn test_mod() {
+    let grid: Grid<Garden> = Grid::parse(&["...", "...", "#.."]).unwrap();
+
+    assert_eq!(*grid.get_wrapping(-1, 0), Garden::Rocks);
+    assert_eq!(*grid.get_wrapping(-4, 0), Garden::Rocks);
+    assert_eq!(*grid.get_wrapping(-4, 1), Garden::Plot);
+}
+
+/// The plots reachable after exactly `steps` steps on the infinitely repeated grid, found by a
+/// breadth-first search that counts every plot within `steps` of the start at the same parity
+#[cfg(test)]
+fn brute_force_reachable(day: &Day21, steps: usize) -> usize {
+    let (start_i, start_j) = (day.start.0 as i64, day.start.1 as i64);
+
+    let mut seen = std::collections::HashSet::from([(start_i, start_j)]);
+    let mut frontier = vec![(start_i, start_j)];
+    let mut count = 0;
+
+    for step in 0..=steps {
+        if step % 2 == steps % 2 {
+            count += frontier.len();
+        }
+
+        let mut next = vec![];
+        for (i, j) in frontier {
+            for (ni, nj) in [(i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)] {
+                if *day.grid.get_wrapping(ni, nj) == Garden::Plot && seen.insert((ni, nj)) {
+                    next.push((ni, nj));
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    return count;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_extrapolate_matches_brute_force(
+        seed in proptest::num::u64::ANY,
+        size in 2..=6usize,
+        grids in 0..=5usize,
+    ) {
+        let mut generator = Generator::new(seed, Some(2 * size + 1));
+        let day = Day21::parse(&generate(&mut generator)).unwrap();
+
+        let steps = size + grids * (2 * size + 1);
+        proptest::prop_assert_eq!(extrapolate(&day, steps).unwrap(), brute_force_reachable(&day, steps));
+    }
+}
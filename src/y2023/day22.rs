@@ -0,0 +1,378 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    animate::Frames,
+    generate::Generator,
+    geometry::Point3,
+    grid::Grid,
+    parse::{integer, key_value, map, pair, parse_lines, preceded, tag, try_map, Parser},
+    profile,
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day22 {
+    bricks: Vec<Brick>,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            bricks: parse_lines(input, brick())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn animate(&self, frames: &mut dyn Frames) -> Result<bool> {
+        animate(self, frames);
+        return Ok(true);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 22,
+    solution: Day22,
+    input: "data/day22.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day22_demo.txt",
+            expected: Answer::Integer(5),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day22_demo.txt",
+            expected: Answer::Integer(7),
+        },
+    ],
+}
+
+fn part1(day: &Day22) -> Result<usize> {
+    let dropped = drop_bricks(&day.bricks, |_, _| {});
+
+    return Ok(dropped
+        .iter()
+        .filter(|b| b.safe_to_remove(&dropped))
+        .count());
+}
+
+fn part2(day: &Day22) -> Result<usize> {
+    let dropped = drop_bricks(&day.bricks, |_, _| {});
+
+    return Ok(dropped
+        .iter()
+        .fold(0, |acc, brick| acc + brick.falls(&dropped)));
+}
+
+/// The bricks once they have all fallen, lowest first. `on_drop` is given the bricks that have
+/// landed and those still to fall each time one lands
+fn drop_bricks(bricks: &[Brick], mut on_drop: impl FnMut(&[Brick], &[Brick])) -> Vec<Brick> {
+    let mut bricks = bricks.to_vec();
+
+    let _span = profile::span("drop bricks");
+
+    bricks.sort_by_key(|b| b.first.z.min(b.second.z));
+
+    let mut dropped = vec![];
+
+    for (n, brick) in bricks.iter().enumerate() {
+        let mut brick = brick.clone();
+        let lowest = brick.highest_z(&dropped);
+
+        let diff = brick.second.z - brick.first.z;
+
+        brick.first.z = lowest + 1;
+        brick.second.z = brick.first.z + diff;
+        dropped.push(brick);
+
+        on_drop(&dropped, &bricks[n + 1..]);
+    }
+
+    return dropped;
+}
+
+/// The stack seen from the front and from the side as each brick lands, with landed bricks as
+/// `#` and those still falling as `+`
+fn animate(day: &Day22, frames: &mut dyn Frames) {
+    let size = |axis: fn(&Point3) -> i64| {
+        day.bricks
+            .iter()
+            .map(|b| axis(&b.second))
+            .max()
+            .unwrap_or(0) as usize
+            + 1
+    };
+    let (width, depth, height) = (size(|p| p.x), size(|p| p.y), size(|p| p.z));
+
+    let draw = |landed: &[Brick], falling: &[Brick]| {
+        // Columns are x then y, with a gap between the two views. Row 0 is the top
+        let mut view = Grid::new(width + 3 + depth, height, ' ');
+        for i in 0..height {
+            for j in 0..width {
+                view[(i, j)] = '.';
+            }
+            for j in 0..depth {
+                view[(i, width + 3 + j)] = '.';
+            }
+        }
+
+        for (bricks, c) in [(falling, '+'), (landed, '#')] {
+            for brick in bricks {
+                for z in brick.first.z..=brick.second.z {
+                    let i = height - 1 - z as usize;
+                    for x in brick.first.x..=brick.second.x {
+                        view[(i, x as usize)] = c;
+                    }
+                    for y in brick.first.y..=brick.second.y {
+                        view[(i, width + 3 + y as usize)] = c;
+                    }
+                }
+            }
+        }
+
+        // The ground at z = 0
+        for j in 0..width {
+            view[(height - 1, j)] = '-';
+        }
+        for j in 0..depth {
+            view[(height - 1, width + 3 + j)] = '-';
+        }
+
+        return view.to_string();
+    };
+
+    frames.push("start".to_string(), draw(&[], &day.bricks));
+
+    drop_bricks(&day.bricks, |landed, falling| {
+        frames.push(
+            format!("{} of {} bricks landed", landed.len(), day.bricks.len()),
+            draw(landed, falling),
+        );
+    });
+}
+
+/// `x,y,z`
+fn point<'a>() -> impl Parser<'a, Point3> {
+    return map(
+        pair(
+            integer(),
+            pair(preceded(tag(","), integer()), preceded(tag(","), integer())),
+        ),
+        |(x, (y, z))| Point3::new(x, y, z),
+    );
+}
+
+/// `1,0,1~1,2,1`
+fn brick<'a>() -> impl Parser<'a, Brick> {
+    return try_map(key_value(point(), tag("~"), point()), |(first, second)| {
+        if first.x > second.x || first.y > second.y || first.z > second.z {
+            return Err("the first end of a brick must not be past the second".to_string());
+        }
+
+        return Ok(Brick { first, second });
+    });
+}
+
+#[derive(PartialEq, Clone, Eq, Hash)]
+struct Brick {
+    pub first: Point3,
+    pub second: Point3,
+}
+
+impl Brick {
+    fn intersects_xy(&self, other: &Self) -> bool {
+        return self.first.x <= other.second.x
+            && self.second.x >= other.first.x
+            && self.first.y <= other.second.y
+            && self.second.y >= other.first.y;
+    }
+
+    fn highest_z(&self, others: &Vec<Self>) -> i64 {
+        return others
+            .iter()
+            .filter(|b| *b != self)
+            .filter(|&b| self.intersects_xy(b))
+            .map(|b| b.second.z)
+            .max()
+            .unwrap_or(0);
+    }
+
+    fn above(&self, others: &Vec<Self>) -> Vec<Self> {
+        return others
+            .iter()
+            .filter(|b| *b != self && self.intersects_xy(b) && b.first.z == self.second.z + 1)
+            .cloned()
+            .collect();
+    }
+
+    fn below(&self, others: &Vec<Self>) -> Vec<Self> {
+        return others
+            .iter()
+            .filter(|b| *b != self && self.intersects_xy(b) && b.second.z == self.first.z - 1)
+            .cloned()
+            .collect();
+    }
+
+    fn safe_to_remove(&self, others: &Vec<Self>) -> bool {
+        let above = self.above(others);
+
+        if above.is_empty() {
+            return true;
+        }
+
+        let to_check = others.iter().filter(|b| *b != self).cloned().collect();
+
+        for above in above.iter() {
+            let below = above.below(&to_check);
+            if below.is_empty() {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    fn falls(&self, others: &Vec<Self>) -> usize {
+        let mut fallen = HashSet::new();
+        let mut stack = VecDeque::new();
+
+        stack.push_back(self.clone());
+
+        let mut remaining = others.clone();
+
+        while let Some(brick) = stack.pop_front() {
+            if fallen.contains(&brick) {
+                continue;
+            }
+
+            fallen.insert(brick.clone());
+            remaining.retain(|p| *p != brick);
+
+            let new_fallen: Vec<Brick> = brick
+                .above(&remaining)
+                .iter()
+                .filter(|b| b.below(&remaining).is_empty())
+                .cloned()
+                .collect();
+
+            stack.extend(new_fallen);
+        }
+
+        return fallen.len() - 1;
+    }
+}
+
+/// A snapshot of `size` falling bricks, none of them overlapping
+fn generate(generator: &mut Generator) -> String {
+    let mut bottom = 1;
+    let mut lines = vec![];
+
+    for _ in 0..generator.size_or(1200) {
+        let mut first = [generator.range(0..=9), generator.range(0..=9), bottom];
+        let mut second = first;
+
+        // Bricks are lines along one axis, inside a 10 by 10 column
+        let axis = generator.range(0..3);
+        let length = generator.range(0..=3);
+        if axis < 2 && first[axis] + length > 9 {
+            first[axis] -= length;
+        }
+        second[axis] = first[axis] + length;
+
+        lines.push(format!(
+            "{},{},{}~{},{},{}",
+            first[0], first[1], first[2], second[0], second[1], second[2]
+        ));
+
+        // Each brick starts above the last, so they are all apart until they fall
+        bottom = second[2] + generator.range(1..=3);
+    }
+
+    generator.shuffle(&mut lines);
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day22_demo.txt";
+    let count = part1(&crate::load(path)).unwrap();
+    assert_eq!(5, count);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day22_demo.txt";
+    let count = part2(&crate::load(path)).unwrap();
+    assert_eq!(7, count);
+}
+
+/// How many bricks fall when each brick is taken away, found by letting the rest of the stack
+/// settle again without it and seeing which bricks moved
+#[cfg(test)]
+fn brute_force_falls(dropped: &[Brick]) -> Vec<usize> {
+    // Where each brick comes to rest, kept in the order given so bricks can be told apart even
+    // when one falls into the place another left
+    fn settle(bricks: &[&Brick]) -> Vec<Brick> {
+        let mut order: Vec<usize> = (0..bricks.len()).collect();
+        order.sort_by_key(|&k| bricks[k].first.z);
+
+        let mut settled: Vec<Brick> = bricks.iter().map(|&b| b.clone()).collect();
+        for (n, &k) in order.iter().enumerate() {
+            let lowest = order[..n]
+                .iter()
+                .filter(|&&below| settled[k].intersects_xy(&settled[below]))
+                .map(|&below| settled[below].second.z)
+                .max()
+                .unwrap_or(0);
+
+            let brick = &mut settled[k];
+            brick.second.z -= brick.first.z - (lowest + 1);
+            brick.first.z = lowest + 1;
+        }
+
+        return settled;
+    }
+
+    return (0..dropped.len())
+        .map(|removed| {
+            let rest: Vec<&Brick> = (0..dropped.len())
+                .filter(|&k| k != removed)
+                .map(|k| &dropped[k])
+                .collect();
+
+            return settle(&rest)
+                .iter()
+                .zip(&rest)
+                .filter(|(after, before)| after != *before)
+                .count();
+        })
+        .collect();
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_parts_match_brute_force(seed in proptest::num::u64::ANY, bricks in 1..=25usize) {
+        let mut generator = Generator::new(seed, Some(bricks));
+        let day = Day22::parse(&generate(&mut generator)).unwrap();
+
+        let falls = brute_force_falls(&drop_bricks(&day.bricks, |_, _| {}));
+        proptest::prop_assert_eq!(part1(&day).unwrap(), falls.iter().filter(|&&f| f == 0).count());
+        proptest::prop_assert_eq!(part2(&day).unwrap(), falls.iter().sum::<usize>());
+    }
+}
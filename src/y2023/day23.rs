@@ -0,0 +1,491 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
+};
+
+use crate::{
+    generate::Generator,
+    geometry::{Direction, Point},
+    grid::Grid,
+    lines, profile,
+    render::{Colour, Picture},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day23 {
+    map: Map,
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            map: Map::new(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+
+    fn render(&self) -> Result<Option<Picture>> {
+        return Ok(Some(render(self)?));
+    }
+}
+
+register! {
+    year: 2023,
+    day: 23,
+    solution: Day23,
+    input: "data/day23.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day23_demo.txt",
+            expected: Answer::Integer(94),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day23_demo.txt",
+            expected: Answer::Integer(154),
+        },
+    ],
+}
+
+fn part1(day: &Day23) -> Result<usize> {
+    return day.map.longest_path_dfs();
+}
+
+fn part2(day: &Day23) -> Result<usize> {
+    return day.map.longest_bath_branches_dfs();
+}
+
+struct Map {
+    map: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Map {
+    fn new(input: &Vec<String>) -> Result<Self> {
+        let map = Grid::parse_with(input, |c| "#.><^v".contains(c).then_some(c))?;
+
+        let start = Point { x: 1, y: 0 };
+
+        let end = Point {
+            x: map.width() as i64 - 2,
+            y: map.height() as i64 - 1,
+        };
+
+        let map = Self { map, start, end };
+
+        if map.tile(&start) != Some(&'.') {
+            return Err(Error::parse(0, 1, "expected the start `.` in the top row"));
+        }
+
+        if map.tile(&end) != Some(&'.') {
+            return Err(Error::parse(
+                map.map.height().saturating_sub(1),
+                map.map.width().saturating_sub(2),
+                "expected the end `.` in the bottom row",
+            ));
+        }
+
+        return Ok(map);
+    }
+
+    /// The tile at `p`, or `None` off the edge of the map
+    fn tile(&self, p: &Point) -> Option<&char> {
+        return self.map.get_point(*p);
+    }
+
+    fn neighbours(&self, p: &Point) -> Vec<Point> {
+        match self.tile(p).unwrap() {
+            '>' => return vec![p.step(Direction::East)],
+            '<' => return vec![p.step(Direction::West)],
+            '^' => return vec![p.step(Direction::North)],
+            'v' => return vec![p.step(Direction::South)],
+            _ => {}
+        }
+
+        let mut neighbours = Vec::new();
+        for d in Direction::ALL {
+            let p = p.step(d);
+            match self.tile(&p) {
+                None => continue,
+                Some(c) => match (c, d) {
+                    // We can't go back up a slope, so I wrote it sort
+                    // of the opposite as one normally would. The 5
+                    // conditions below are the invalid neighbors and
+                    // then all others would be valid.
+                    ('#', _) => continue,
+                    ('>', Direction::West) => continue,
+                    ('<', Direction::East) => continue,
+                    ('^', Direction::South) => continue,
+                    ('v', Direction::North) => continue,
+                    _ => neighbours.push(p),
+                },
+            }
+        }
+        return neighbours;
+    }
+
+    fn dfs(&self, p: Point, seen: &mut HashSet<Point>, steps: usize, ends: &mut Vec<usize>) {
+        if p == self.end {
+            ends.push(steps);
+            return;
+        }
+
+        if seen.contains(&p) {
+            return;
+        }
+
+        seen.insert(p);
+
+        for n in self.neighbours(&p) {
+            if seen.contains(&n) {
+                continue;
+            }
+
+            self.dfs(n, seen, steps + 1, ends);
+        }
+
+        seen.remove(&p);
+    }
+
+    fn longest_path_dfs(&self) -> Result<usize> {
+        let _span = profile::span("dfs");
+
+        let mut ends = vec![];
+        let mut seen = HashSet::new();
+
+        self.dfs(self.start, &mut seen, 0, &mut ends);
+        return longest(&ends);
+    }
+
+    /// Every tile of the longest hike in part 1, from the start to the end
+    fn longest_hike(&self) -> Result<Vec<Point>> {
+        let mut path = vec![];
+        let mut seen = HashSet::new();
+        let mut best = None;
+
+        self.dfs_hike(self.start, &mut seen, &mut path, &mut best);
+        return best.ok_or_else(|| Error::unsolvable("there is no path from the start to the end"));
+    }
+
+    /// Like `dfs`, but keeping the tiles walked so that the longest hike can be drawn
+    fn dfs_hike(
+        &self,
+        p: Point,
+        seen: &mut HashSet<Point>,
+        path: &mut Vec<Point>,
+        best: &mut Option<Vec<Point>>,
+    ) {
+        if seen.contains(&p) {
+            return;
+        }
+
+        path.push(p);
+
+        if p == self.end {
+            if best.as_ref().is_none_or(|b| path.len() > b.len()) {
+                *best = Some(path.clone());
+            }
+            path.pop();
+            return;
+        }
+
+        seen.insert(p);
+
+        for n in self.neighbours(&p) {
+            self.dfs_hike(n, seen, path, best);
+        }
+
+        seen.remove(&p);
+        path.pop();
+    }
+
+    fn neighbours_pt2(&self, p: &Point) -> Vec<Point> {
+        let mut neighbours = vec![];
+
+        for d in p.neighbours() {
+            match self.tile(&d) {
+                None => continue,
+                Some(c) => match c {
+                    '#' => continue,
+                    _ => neighbours.push(d),
+                },
+            }
+        }
+
+        return neighbours;
+        todo!();
+    }
+
+    fn find_branching_edges(&self) -> HashMap<Point, Vec<(Point, usize)>> {
+        let _span = profile::span("find branching edges");
+
+        let map = self
+            .map
+            .iter()
+            .filter(|(_, c)| **c != '#')
+            .map(|((y, x), _)| {
+                let p = Point::new(x as i64, y as i64);
+                let n = self.neighbours_pt2(&p).len();
+                (p, n)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let nodes = map
+            .iter()
+            .filter(|(_, n)| **n != 2)
+            .map(|(p, _)| *p)
+            .collect::<HashSet<_>>();
+
+        let mut edges: HashMap<Point, Vec<(Point, usize)>> = HashMap::new();
+
+        for node in nodes.iter() {
+            for mut neighbour in self.neighbours_pt2(node) {
+                let mut prev = *node;
+
+                let mut dist = 0;
+
+                loop {
+                    dist += 1;
+
+                    let neighbours = self.neighbours_pt2(&neighbour);
+                    let neighbours = neighbours
+                        .iter()
+                        .filter(|&n| *n != prev)
+                        .collect::<Vec<_>>();
+
+                    if neighbours.len() != 1 {
+                        edges.entry(*node).or_default().push((neighbour, dist));
+                        break;
+                    }
+
+                    prev = neighbour;
+                    neighbour = *neighbours[0];
+                }
+            }
+        }
+
+        return edges;
+    }
+
+    fn longest_bath_branches_dfs(&self) -> Result<usize> {
+        let edges = self.find_branching_edges();
+
+        let _span = profile::span("dfs");
+
+        let mut seen = HashSet::new();
+        let mut ends = Vec::new();
+        self.dfs_pt2(&edges, self.start, &mut seen, 0, &mut ends);
+        return longest(&ends);
+    }
+
+    fn dfs_pt2(
+        &self,
+        edges: &HashMap<Point, Vec<(Point, usize)>>,
+        p: Point,
+        seen: &mut HashSet<Point>,
+        steps: usize,
+        ends: &mut Vec<usize>,
+    ) {
+        if p == self.end {
+            ends.push(steps);
+            return;
+        }
+
+        if seen.contains(&p) {
+            return;
+        }
+
+        seen.insert(p);
+
+        let neighbours = match edges.get(&p) {
+            Some(x) => x,
+            None => &vec![],
+        };
+
+        for (neighbour, cost) in neighbours {
+            if seen.contains(neighbour) {
+                continue;
+            }
+            self.dfs_pt2(edges, *neighbour, seen, steps + cost, ends);
+        }
+
+        seen.remove(&p);
+    }
+}
+
+/// The forest in green, the slopes in yellow and part 1's longest hike in red
+fn render(day: &Day23) -> Result<Picture> {
+    let mut picture = Picture::from_grid(&day.map.map, |&c| match c {
+        '#' => Colour::GREEN.mix(Colour::BLACK, 0.5),
+        '.' => Colour::BLACK,
+        _ => Colour::YELLOW,
+    });
+
+    let hike = day.map.longest_hike()?;
+    picture.path(
+        hike.iter().map(|p| (p.y as usize, p.x as usize)).collect(),
+        Colour::RED,
+    );
+
+    return Ok(picture);
+}
+
+fn longest(ends: &[usize]) -> Result<usize> {
+    return ends
+        .iter()
+        .max()
+        .copied()
+        .ok_or_else(|| Error::unsolvable("there is no path from the start to the end"));
+}
+
+/// A maze of `size` by `size` cells with a few loops, drawn with its walls as a `2 * size + 1`
+/// square map. Slopes next to each junction point away from the start, so part 1 can only go
+/// forwards
+fn generate(generator: &mut Generator) -> String {
+    let cells = generator.size_or(20).max(2);
+    let side = 2 * cells + 1;
+
+    let mut map = Grid::new(side, side, '#');
+    let mut visited = Grid::new(cells, cells, false);
+    let mut stack = vec![(0, 0)];
+    visited[(0, 0)] = true;
+    map[(1, 1)] = '.';
+
+    while let Some(&(i, j)) = stack.last() {
+        let next: Vec<(usize, usize)> = visited
+            .neighbours_4(i, j)
+            .filter(|&n| !visited[n])
+            .collect();
+
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let n = *generator.choose(&next);
+        visited[n] = true;
+        map[(2 * n.0 + 1, 2 * n.1 + 1)] = '.';
+        map[(i + n.0 + 1, j + n.1 + 1)] = '.';
+        stack.push(n);
+    }
+
+    // Knock through a few walls between cells to make loops
+    for _ in 0..cells / 2 {
+        let i = generator.range(1..side - 1);
+        let j = generator.range(1..side - 1);
+        if (i + j) % 2 == 1 {
+            map[(i, j)] = '.';
+        }
+    }
+
+    map[(0, 1)] = '.';
+    map[(side - 1, side - 2)] = '.';
+
+    // How far each tile is from the start, to point the slopes
+    let mut distance = Grid::new(side, side, usize::MAX);
+    let mut queue = VecDeque::from([(0, 1)]);
+    distance[(0, 1)] = 0;
+    while let Some(p) = queue.pop_front() {
+        let neighbours: Vec<(usize, usize)> = map.neighbours_4(p.0, p.1).collect();
+        for n in neighbours {
+            if map[n] != '#' && distance[n] == usize::MAX {
+                distance[n] = distance[p] + 1;
+                queue.push_back(n);
+            }
+        }
+    }
+
+    for i in 1..side - 1 {
+        for j in 1..side - 1 {
+            let paths: Vec<(usize, usize)> =
+                map.neighbours_4(i, j).filter(|&n| map[n] != '#').collect();
+            if map[(i, j)] == '#' || paths.len() < 3 {
+                continue;
+            }
+
+            // The start and the end stay flat
+            for (a, b) in paths.into_iter().filter(|&(a, _)| a > 0 && a < side - 1) {
+                let away = distance[(a, b)] > distance[(i, j)];
+                map[(a, b)] = match (a.cmp(&i), b.cmp(&j), away) {
+                    (Ordering::Less, _, true) | (Ordering::Greater, _, false) => '^',
+                    (Ordering::Greater, _, true) | (Ordering::Less, _, false) => 'v',
+                    (_, Ordering::Less, true) | (_, Ordering::Greater, false) => '<',
+                    _ => '>',
+                };
+            }
+        }
+    }
+
+    return map.to_string();
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day23_demo.txt";
+    let max = part1(&crate::load(path)).unwrap();
+    assert_eq!(max, 94);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day23_demo.txt";
+    let max = part2(&crate::load(path)).unwrap();
+    assert_eq!(max, 154);
+}
+
+/// The longest hike in part 2, found by trying every path tile by tile, without collapsing the
+/// corridors between junctions
+#[cfg(test)]
+fn brute_force_longest(map: &Grid<char>) -> usize {
+    fn walk(map: &Grid<char>, p: (usize, usize), seen: &mut Grid<bool>) -> Option<usize> {
+        if p == (map.height() - 1, map.width() - 2) {
+            return Some(0);
+        }
+
+        seen[p] = true;
+        let mut longest = None;
+        let neighbours: Vec<(usize, usize)> = map.neighbours_4(p.0, p.1).collect();
+        for n in neighbours {
+            if map[n] == '#' || seen[n] {
+                continue;
+            }
+
+            if let Some(steps) = walk(map, n, seen) {
+                longest = longest.max(Some(steps + 1));
+            }
+        }
+        seen[p] = false;
+
+        return longest;
+    }
+
+    let mut seen = Grid::new(map.width(), map.height(), false);
+    return walk(map, (0, 1), &mut seen).unwrap();
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, cells in 2..=5usize) {
+        let mut generator = Generator::new(seed, Some(cells));
+        let day = Day23::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_longest(&day.map.map));
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "linalg")]
+use peroxide::fuga::matrix;
+#[cfg(feature = "linalg")]
+use peroxide::fuga::Shape;
+#[cfg(feature = "linalg")]
+use peroxide::prelude::SimplerLinearAlgebra;
+
+use crate::{
+    generate::Generator,
+    parse::{key_value, map, optional_spaces, pair, parse_lines, preceded, signed, tag, Parser},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day24 {
+    hail: Vec<Hailstone>,
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            hail: parse_lines(input, hailstone())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self, 200_000_000_000_000.0, 400_000_000_000_000.0)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        // NOTE: On my machine, this outputs 769281292688187.3 for my input
+        // The correct solution is 769281292688187
+        // Due to the massive size of the input numbers, there is significant floating point error
+        return Ok(part2(self)?.into());
+    }
+
+    fn part1_demo(&self) -> Result<Answer> {
+        return Ok(part1(self, 7.0, 27.0)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 24,
+    solution: Day24,
+    input: "data/day24.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day24_demo.txt",
+            expected: Answer::Integer(2),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day24_demo.txt",
+            expected: Answer::Float(47.0),
+        },
+    ],
+}
+
+fn part1(day: &Day24, min: f64, max: f64) -> Result<usize> {
+    let hail = &day.hail;
+
+    let mut count = 0;
+
+    for i in 0..hail.len() {
+        for j in i + 1..hail.len() {
+            if let Some(point) = hail[i].intersect_xy(&hail[j]) {
+                match point {
+                    Intersection::Parallel => count += 1,
+                    Intersection::Point(point) => {
+                        if point.x > min
+                            && point.x < max
+                            && point.y > min
+                            && point.y < max
+                            && !hail[i].in_past_xy(&point)
+                            && !hail[j].in_past_xy(&point)
+                        {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return Ok(count);
+}
+
+pub fn part2(day: &Day24) -> Result<f64> {
+    let hail = &day.hail;
+
+    if hail.len() < 3 {
+        return Err(Error::unsolvable(format!(
+            "at least 3 hailstones are needed, found {}",
+            hail.len()
+        )));
+    }
+
+    let mut p0 = hail[0].clone();
+    let mut p1 = hail[1].clone();
+    let mut p2 = hail[2].clone();
+
+    let centoid = Hailstone {
+        x: (p0.x + p1.x + p2.x) / 3.0,
+        y: (p0.y + p1.y + p2.y) / 3.0,
+        z: (p0.z + p1.z + p2.z) / 3.0,
+        vx: 0.0,
+        vy: 0.0,
+        vz: 0.0,
+    };
+
+    p0.recenter_around_origin(&centoid);
+    p1.recenter_around_origin(&centoid);
+    p2.recenter_around_origin(&centoid);
+
+    let b: Vec<f64> = vec![
+        (p0.y * p0.vx - p1.y * p1.vx) - (p0.x * p0.vy - p1.x * p1.vy),
+        (p0.y * p0.vx - p2.y * p2.vx) - (p0.x * p0.vy - p2.x * p2.vy),
+        (p0.z * p0.vx - p1.z * p1.vx) - (p0.x * p0.vz - p1.x * p1.vz),
+        (p0.z * p0.vx - p2.z * p2.vx) - (p0.x * p0.vz - p2.x * p2.vz),
+        (p0.z * p0.vy - p1.z * p1.vy) - (p0.y * p0.vz - p1.y * p1.vz),
+        (p0.z * p0.vy - p2.z * p2.vy) - (p0.y * p0.vz - p2.y * p2.vz),
+    ];
+
+    // let b = matrix(b, 6, 1, Shape::Row);
+
+    let a = vec![
+        vec![
+            p1.vy - p0.vy,
+            p0.vx - p1.vx,
+            0.0,
+            p0.y - p1.y,
+            p1.x - p0.x,
+            0.0,
+        ],
+        vec![
+            p2.vy - p0.vy,
+            p0.vx - p2.vx,
+            0.0,
+            p0.y - p2.y,
+            p2.x - p0.x,
+            0.0,
+        ],
+        vec![
+            p1.vz - p0.vz,
+            0.0,
+            p0.vx - p1.vx,
+            p0.z - p1.z,
+            0.0,
+            p1.x - p0.x,
+        ],
+        vec![
+            p2.vz - p0.vz,
+            0.0,
+            p0.vx - p2.vx,
+            p0.z - p2.z,
+            0.0,
+            p2.x - p0.x,
+        ],
+        vec![
+            0.0,
+            p1.vz - p0.vz,
+            p0.vy - p1.vy,
+            0.0,
+            p0.z - p1.z,
+            p1.y - p0.y,
+        ],
+        vec![
+            0.0,
+            p2.vz - p0.vz,
+            p0.vy - p2.vy,
+            0.0,
+            p0.z - p2.z,
+            p2.y - p0.y,
+        ],
+    ];
+
+    let r = solve(a, b);
+
+    let sum = r[0] + centoid.x + r[1] + centoid.y + r[2] + centoid.z;
+
+    if !sum.is_finite() {
+        return Err(Error::unsolvable(
+            "the hailstones do not give a single throw",
+        ));
+    }
+
+    return Ok(sum);
+}
+
+/// Solves `a x = b` with peroxide
+#[cfg(feature = "linalg")]
+fn solve(a: Vec<Vec<f64>>, b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+    let a = matrix(a.concat(), n, n, Shape::Row);
+
+    return a.solve(&b);
+}
+
+/// Solves `a x = b` by Gaussian elimination with partial pivoting. A singular `a` gives
+/// non-finite values
+#[cfg(not(feature = "linalg"))]
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap();
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col].clone();
+
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+
+            for (cell, pivot) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *cell -= factor * pivot;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - known) / a[row][row];
+    }
+
+    return x;
+}
+
+/// A hailstone's position and velocity
+#[derive(Clone)]
+struct Hailstone {
+    x: f64,
+    y: f64,
+    z: f64,
+    vx: f64,
+    vy: f64,
+    vz: f64,
+}
+
+/// `x, y, z`, with any number of spaces after each comma
+fn coordinates<'a>() -> impl Parser<'a, (f64, f64, f64)> {
+    let coordinate = || preceded(optional_spaces(), signed());
+
+    return map(
+        pair(
+            coordinate(),
+            pair(
+                preceded(tag(","), coordinate()),
+                preceded(tag(","), coordinate()),
+            ),
+        ),
+        |(x, (y, z))| (x, y, z),
+    );
+}
+
+/// `19, 13, 30 @ -2,  1, -2`
+fn hailstone<'a>() -> impl Parser<'a, Hailstone> {
+    return map(
+        key_value(coordinates(), tag(" @"), coordinates()),
+        |((x, y, z), (vx, vy, vz))| Hailstone {
+            x,
+            y,
+            z,
+            vx,
+            vy,
+            vz,
+        },
+    );
+}
+
+impl Hailstone {
+    fn slope_intercept(&self) -> (f64, f64) {
+        let slope_self = self.vy / self.vx;
+        let intercept_self = self.y - slope_self * self.x;
+
+        return (slope_self, intercept_self);
+    }
+
+    fn intersect_xy(&self, other: &Hailstone) -> Option<Intersection> {
+        let (slope_self, intercept_self) = self.slope_intercept();
+        let (slope_other, intercept_other) = other.slope_intercept();
+
+        if slope_self == slope_other && intercept_self == intercept_other {
+            return Some(Intersection::Parallel);
+        } else if slope_self == slope_other {
+            return None;
+        }
+
+        // Solve for x and y.
+        let x = (intercept_other - intercept_self) / (slope_self - slope_other);
+        let y = slope_self * x + intercept_self;
+        return Some(Intersection::Point(Hailstone {
+            x,
+            y,
+            z: 0.0,
+            vx: 0.0,
+            vy: 0.0,
+            vz: 0.0,
+        }));
+    }
+
+    fn in_past_xy(&self, point: &Hailstone) -> bool {
+        let x = point.x - self.x;
+        let y = point.y - self.y;
+
+        let x = x / self.vx;
+        let y = y / self.vy;
+
+        return x < 0.0 && y < 0.0;
+    }
+
+    fn recenter_around_origin(&mut self, centroid: &Hailstone) {
+        self.x -= centroid.x;
+        self.y -= centroid.y;
+        self.z -= centroid.z;
+    }
+}
+
+enum Intersection {
+    Point(Hailstone),
+    Parallel,
+}
+
+/// `size` hailstones, all of which a rock thrown from a random point will hit
+fn generate(generator: &mut Generator) -> String {
+    // Velocities are never zero, and never match the rock's, so that the paths are not parallel to
+    // an axis and every collision happens at a single point in time
+    let velocity = |generator: &mut Generator, not: i64| -> i64 {
+        return loop {
+            let v = generator.range(-300..=300);
+            if v != 0 && v != not {
+                break v;
+            }
+        };
+    };
+
+    let rock = [
+        generator.range(200_000_000_000_000..=400_000_000_000_000_i64),
+        generator.range(200_000_000_000_000..=400_000_000_000_000_i64),
+        generator.range(200_000_000_000_000..=400_000_000_000_000_i64),
+    ];
+    let rock_velocity = [
+        velocity(generator, 0),
+        velocity(generator, 0),
+        velocity(generator, 0),
+    ];
+
+    let mut times = HashSet::new();
+    let mut lines = vec![];
+
+    while lines.len() < generator.size_or(300) {
+        let time = generator.range(100_000_000_000..1_000_000_000_000_i64);
+        if !times.insert(time) {
+            continue;
+        }
+
+        let mut position = [0; 3];
+        let mut hail_velocity = [0; 3];
+        for axis in 0..3 {
+            hail_velocity[axis] = velocity(generator, rock_velocity[axis]);
+            position[axis] = rock[axis] + (rock_velocity[axis] - hail_velocity[axis]) * time;
+        }
+
+        lines.push(format!(
+            "{}, {}, {} @ {}, {}, {}",
+            position[0],
+            position[1],
+            position[2],
+            hail_velocity[0],
+            hail_velocity[1],
+            hail_velocity[2]
+        ));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day24_demo.txt";
+    let count = part1(&crate::load(path), 7.0, 27.0).unwrap();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day24_demo.txt";
+    let count = part2(&crate::load(path)).unwrap();
+    assert_eq!(count, 47.0);
+}
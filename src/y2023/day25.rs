@@ -0,0 +1,315 @@
+#[cfg(not(feature = "graph"))]
+use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "graph")]
+use rustworkx_core::{
+    connectivity::stoer_wagner_min_cut,
+    petgraph::{graph::UnGraph, Graph, Undirected},
+    Result as GraphResult,
+};
+
+use crate::{
+    generate::Generator,
+    parse::{identifier, key_value, parse_lines, separated, spaces, tag},
+    profile,
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day25 {
+    /// Each component and the components it is wired to
+    connections: Vec<(String, Vec<String>)>,
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self> {
+        // `jqt: rhn xhk nvd`
+        let connection = key_value(identifier(), tag(": "), separated(identifier(), spaces()));
+
+        let connections = parse_lines(input, connection)?
+            .into_iter()
+            .map(|(k, vv)| (k.to_string(), vv.iter().map(|v| v.to_string()).collect()))
+            .collect();
+
+        return Ok(Self { connections });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(Answer::None);
+    }
+}
+
+register! {
+    year: 2023,
+    day: 25,
+    solution: Day25,
+    input: "data/day25.txt",
+    demos: &[Demo {
+        part: 1,
+        input: "data_demo/day25_demo.txt",
+        expected: Answer::Integer(54),
+    }],
+}
+
+fn part1(day: &Day25) -> Result<usize> {
+    let span = profile::span("build graph");
+
+    let mut nodes: HashMap<&str, usize> = HashMap::new();
+    let mut wires = vec![];
+
+    for (k, vv) in &day.connections {
+        for v in vv {
+            let count = nodes.len();
+            let a = *nodes.entry(k.as_str()).or_insert(count);
+            let count = nodes.len();
+            let b = *nodes.entry(v.as_str()).or_insert(count);
+
+            wires.push((a, b));
+        }
+    }
+
+    let total_nodes = nodes.len();
+
+    drop(span);
+    let _span = profile::span("min cut");
+
+    let (min_cut, partition1) = match min_cut(total_nodes, &wires) {
+        Some(x) => x,
+        None => return Err(Error::unsolvable("the graph has no cut")),
+    };
+
+    if min_cut != 3 {
+        return Err(Error::unsolvable(format!(
+            "expected a minimum cut of 3 wires, found {}",
+            min_cut
+        )));
+    }
+
+    let partition2 = total_nodes - partition1;
+
+    return Ok(partition1 * partition2);
+}
+
+/// The fewest wires whose removal splits the `count` components in two, and the size of one of
+/// the two groups, using rustworkx's Stoer-Wagner minimum cut
+#[cfg(feature = "graph")]
+fn min_cut(count: usize, wires: &[(usize, usize)]) -> Option<(usize, usize)> {
+    let mut graph: Graph<(), (), Undirected> = UnGraph::new_undirected();
+
+    let nodes: Vec<_> = (0..count).map(|_| graph.add_node(())).collect();
+    graph.extend_with_edges(wires.iter().map(|&(a, b)| (nodes[a], nodes[b])));
+
+    let min_cut_res: GraphResult<Option<(usize, Vec<_>)>> = stoer_wagner_min_cut(&graph, |_| Ok(1));
+
+    return match min_cut_res {
+        Ok(Some((min_cut, partition))) => Some((min_cut, partition.len())),
+        _ => None,
+    };
+}
+
+/// The fewest wires whose removal splits the `count` components in two, and the size of one of
+/// the two groups.
+///
+/// Every cut separates the first component from some other, so this is the smallest maximum flow
+/// from the first component to any other, each flow found one path of unused wires at a time
+#[cfg(not(feature = "graph"))]
+fn min_cut(count: usize, wires: &[(usize, usize)]) -> Option<(usize, usize)> {
+    if count < 2 {
+        return None;
+    }
+
+    // Each component's wires, as the component at the other end and the wire's index
+    let mut neighbours = vec![vec![]; count];
+    for (w, &(a, b)) in wires.iter().enumerate() {
+        neighbours[a].push((b, w));
+        neighbours[b].push((a, w));
+    }
+
+    let mut best: Option<(usize, usize)> = None;
+
+    for sink in 1..count {
+        // Flow along each wire from its first end to its second: -1, 0 or 1
+        let mut flow = vec![0i8; wires.len()];
+        let mut total = 0;
+
+        // A flow as large as the best cut so far cannot give a smaller one
+        while best.is_none_or(|(cut, _)| total < cut) {
+            let mut from: Vec<Option<(usize, usize)>> = vec![None; count];
+            let mut reached = vec![false; count];
+            reached[0] = true;
+            let mut queue = VecDeque::from([0]);
+
+            while let Some(a) = queue.pop_front() {
+                for &(b, w) in &neighbours[a] {
+                    let spare = match wires[w].0 == a {
+                        true => 1 - flow[w],
+                        false => 1 + flow[w],
+                    };
+
+                    if spare > 0 && !reached[b] {
+                        reached[b] = true;
+                        from[b] = Some((a, w));
+                        queue.push_back(b);
+                    }
+                }
+            }
+
+            if !reached[sink] {
+                let group = reached.iter().filter(|&&r| r).count();
+                best = Some((total, group));
+                break;
+            }
+
+            let mut b = sink;
+            while let Some((a, w)) = from[b] {
+                flow[w] += match wires[w].0 == a {
+                    true => 1,
+                    false => -1,
+                };
+                b = a;
+            }
+            total += 1;
+        }
+    }
+
+    return best;
+}
+
+/// `size` components, at least 10, in two groups joined by exactly three wires
+fn generate(generator: &mut Generator) -> String {
+    let count = generator.size_or(1500).clamp(10, 10_000);
+    let names = generator.names(count, 3, &[]);
+
+    let smallest = (count / 3).max(5);
+    let split = generator.range(smallest..=count - smallest);
+    let groups = [0..split, split..count];
+
+    // Each component is wired to at least four others in its group, so no cut within a group is
+    // as small as the three wires between the groups
+    let mut wires = HashSet::new();
+    let mut degrees = vec![0; count];
+    for group in &groups {
+        for a in group.clone() {
+            while degrees[a] < 4 {
+                let b = generator.range(group.clone());
+                if a != b && wires.insert((a.min(b), a.max(b))) {
+                    degrees[a] += 1;
+                    degrees[b] += 1;
+                }
+            }
+        }
+    }
+
+    let mut crossing = 0;
+    while crossing < 3 {
+        let a = generator.range(groups[0].clone());
+        let b = generator.range(groups[1].clone());
+        if wires.insert((a, b)) {
+            crossing += 1;
+        }
+    }
+
+    // Each wire is listed once, under either of its ends
+    let mut wires: Vec<(usize, usize)> = wires.into_iter().collect();
+    wires.sort();
+
+    let mut wiring: Vec<Vec<&str>> = vec![vec![]; count];
+    for (a, b) in wires {
+        match generator.chance(0.5) {
+            true => wiring[a].push(&names[b]),
+            false => wiring[b].push(&names[a]),
+        }
+    }
+
+    let mut lines: Vec<String> = wiring
+        .iter()
+        .enumerate()
+        .filter(|(_, others)| !others.is_empty())
+        .map(|(a, others)| format!("{}: {}", names[a], others.join(" ")))
+        .collect();
+    generator.shuffle(&mut lines);
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day25_demo.txt";
+    let count = part1(&crate::load(path)).unwrap();
+    assert_eq!(count, 54);
+}
+
+#[test]
+fn test_generate() {
+    let input = Day25::generate(&mut Generator::new(5, Some(40)));
+    let product = part1(&Day25::parse(&input).unwrap()).unwrap();
+
+    // Both groups have at least 40 / 3 components
+    assert!((13..=27).any(|a| a * (40 - a) == product));
+}
+
+/// The product of the group sizes for every way of cutting three wires that splits the
+/// components in two, found by trying every three wires
+#[cfg(test)]
+fn brute_force_cuts(connections: &[(String, Vec<String>)]) -> HashSet<usize> {
+    let wires: Vec<(&str, &str)> = connections
+        .iter()
+        .flat_map(|(a, others)| others.iter().map(move |b| (a.as_str(), b.as_str())))
+        .collect();
+    let components: HashSet<&str> = wires.iter().flat_map(|&(a, b)| [a, b]).collect();
+
+    let mut products = HashSet::new();
+
+    for x in 0..wires.len() {
+        for y in x + 1..wires.len() {
+            for z in y + 1..wires.len() {
+                let start = wires[0].0;
+                let mut group = HashSet::from([start]);
+                let mut stack = vec![start];
+
+                while let Some(a) = stack.pop() {
+                    for (w, &(b, c)) in wires.iter().enumerate() {
+                        let next = match (b == a, c == a) {
+                            (true, _) => c,
+                            (_, true) => b,
+                            _ => continue,
+                        };
+                        if w != x && w != y && w != z && group.insert(next) {
+                            stack.push(next);
+                        }
+                    }
+                }
+
+                if group.len() < components.len() {
+                    products.insert(group.len() * (components.len() - group.len()));
+                }
+            }
+        }
+    }
+
+    return products;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(16))]
+
+    #[test]
+    fn test_part1_matches_brute_force(seed in proptest::num::u64::ANY, size in 10..=14usize) {
+        let mut generator = Generator::new(seed, Some(size));
+        let day = Day25::parse(&generate(&mut generator)).unwrap();
+
+        // A group may have its own three-wire cut, so the minimum cut can be any one of them
+        let products = brute_force_cuts(&day.connections);
+        proptest::prop_assert!(products.contains(&part1(&day).unwrap()));
+    }
+}
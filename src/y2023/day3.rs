@@ -0,0 +1,272 @@
+use crate::{
+    generate::Generator, grid::Grid, lines, solution::register, Answer, Demo, Error, Result,
+    Solution,
+};
+
+pub struct Day3 {
+    chars: Grid<char>,
+}
+
+impl Solution for Day3 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            chars: Grid::parse(&lines(input))?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 3,
+    solution: Day3,
+    input: "data/day3.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day3_demo.txt",
+            expected: Answer::Integer(4361),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day3_demo.txt",
+            expected: Answer::Integer(467835),
+        },
+    ],
+}
+
+fn part1(day: &Day3) -> Result<i32> {
+    let mut engine_sum = 0;
+    let chars = &day.chars;
+
+    let mut coords: Vec<Coord> = vec![];
+    let mut number = vec![];
+
+    for (i, line) in chars.rows().enumerate() {
+        for (j, unit) in line.iter().enumerate() {
+            if unit.is_digit(10) {
+                number.push(unit);
+                coords.push(Coord(i as i32, j as i32));
+            } else {
+                let num_len = number.len();
+
+                if num_len == 0 {
+                    // This is e.g. two . in a row, do nothing!
+                    continue;
+                } else {
+                    // We've hit a . or something following a number. So
+                    // we need to turn the Vec<char> into a String, parse it,
+                    // then figure out which indicies to check to determine if
+                    // this number is an engine part
+
+                    let num_string: String = number.iter().map(|x| *x).collect();
+                    let num_number = num_string.parse::<i32>().map_err(|e| {
+                        Error::parse(coords[0].0 as usize, coords[0].1 as usize, e.to_string())
+                    })?;
+
+                    let coords_to_check = find_coords_to_check(chars, &coords);
+
+                    for coord in coords_to_check {
+                        let char_in_spot = chars[(coord.0 as usize, coord.1 as usize)];
+
+                        if !char_in_spot.is_digit(10) && char_in_spot != '.' {
+                            // Then it is some symbol which isn't a number or a ., so it is an engine part!
+                            engine_sum += num_number;
+                            break; // Don't want to add the number multiple times!
+                        }
+                    }
+
+                    coords = vec![];
+                    number = vec![];
+                }
+            }
+        }
+    }
+
+    return Ok(engine_sum);
+}
+
+/// (i, j)
+#[derive(PartialEq, Clone)]
+struct Coord(i32, i32);
+
+fn find_coords_to_check(chars: &Grid<char>, num_coords: &Vec<Coord>) -> Vec<Coord> {
+    let mut output = vec![];
+
+    for coord in num_coords {
+        for (new_i, new_j) in chars.neighbours_8(coord.0 as usize, coord.1 as usize) {
+            let potential_adjacent = Coord(new_i as i32, new_j as i32);
+
+            if !num_coords.contains(&potential_adjacent) && !output.contains(&potential_adjacent) {
+                output.push(potential_adjacent);
+            }
+        }
+    }
+
+    return output;
+}
+
+fn part2(day: &Day3) -> Result<i32> {
+    let mut engine_sum = 0;
+    let chars = &day.chars;
+
+    for (i, line) in chars.rows().enumerate() {
+        for (j, unit) in line.iter().enumerate() {
+            if *unit != '*' {
+                continue;
+            }
+
+            let coord = vec![Coord(i as i32, j as i32)];
+
+            let coords_to_check = find_coords_to_check(chars, &coord);
+
+            let mut valid_coords = vec![];
+
+            for c in coords_to_check {
+                if chars[(c.0 as usize, c.1 as usize)].is_digit(10) {
+                    valid_coords.push(c.clone());
+                }
+            }
+
+            if !(valid_coords.len() >= 2) {
+                continue;
+            }
+
+            let numbers = find_numbers(&chars, &valid_coords)?;
+
+            if numbers.len() == 2 {
+                let ratio = numbers.iter().fold(1, |acc, x| acc * x);
+
+                engine_sum += ratio;
+            }
+        }
+    }
+
+    return Ok(engine_sum);
+}
+
+fn find_numbers(chars: &Grid<char>, starting_points: &Vec<Coord>) -> Result<Vec<i32>> {
+    let mut valid_nums = vec![];
+
+    for coord in starting_points {
+        let mut js = vec![];
+
+        js.push(coord.1);
+
+        // Look up to two to the right, and then up to two to the left
+
+        let char_to_right = char_at(chars, coord.0, coord.1 + 1);
+
+        let char_to_right_is_digit = char_to_right.is_digit(10);
+
+        if char_to_right_is_digit {
+            js.push(coord.1 + 1);
+
+            let char_to_right = char_at(chars, coord.0, coord.1 + 2);
+
+            let char_to_right_is_digit = char_to_right.is_digit(10);
+
+            if char_to_right_is_digit {
+                js.push(coord.1 + 2);
+            }
+        }
+
+        let char_to_left = char_at(chars, coord.0, coord.1 - 1);
+
+        let char_to_left_is_digit = char_to_left.is_digit(10);
+
+        if char_to_left_is_digit {
+            js.push(coord.1 - 1);
+
+            let char_to_left = char_at(chars, coord.0, coord.1 - 2);
+
+            let char_to_left_is_digit = char_to_left.is_digit(10);
+
+            if char_to_left_is_digit {
+                js.push(coord.1 - 2);
+            }
+        }
+
+        js.sort();
+
+        let mut char_nums = vec![];
+
+        for j in js {
+            char_nums.push(chars[(coord.0 as usize, j as usize)]);
+        }
+
+        let num_string: String = char_nums.iter().map(|x| *x).collect();
+        let num_number = num_string
+            .parse::<i32>()
+            .map_err(|e| Error::parse(coord.0 as usize, coord.1 as usize, e.to_string()))?;
+
+        if !valid_nums.contains(&num_number) {
+            valid_nums.push(num_number);
+        }
+    }
+
+    return Ok(valid_nums);
+}
+
+/// The char at (i, j), treating anything off the grid as empty space
+fn char_at(chars: &Grid<char>, i: i32, j: i32) -> char {
+    return match chars.get_signed(i as i64, j as i64) {
+        Some(c) => *c,
+        None => '.',
+    };
+}
+
+/// A `size` by `size` schematic of numbers and symbols
+fn generate(generator: &mut Generator) -> String {
+    let size = generator.size_or(140).max(2);
+    let mut rows = vec![];
+
+    for _ in 0..size {
+        let mut row = String::new();
+
+        while row.len() < size {
+            let room = size - row.len();
+
+            if room >= 2 && generator.chance(0.15) {
+                // Numbers never touch each other or end a row
+                let digits = generator.range(1..=3.min(room - 1));
+                row.push_str(&generator.word("123456789", 1));
+                row.push_str(&generator.word("0123456789", digits - 1));
+                row.push('.');
+            } else if generator.chance(0.1) {
+                row.push_str(&generator.word("*#+$/@=%&", 1));
+            } else {
+                row.push('.');
+            }
+        }
+
+        rows.push(row);
+    }
+
+    return rows.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let file = "data_demo/day3_demo.txt";
+    let sum = part1(&crate::load(file)).unwrap();
+    assert_eq!(sum, 4361);
+}
+
+#[test]
+fn test_part2() {
+    let file = "data_demo/day3_demo.txt";
+    let sum = part2(&crate::load(file)).unwrap();
+    assert_eq!(sum, 467835);
+}
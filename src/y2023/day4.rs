@@ -0,0 +1,241 @@
+use crate::{
+    generate::Generator,
+    parse::{delimited, integer, map, numbers, pair, parse_lines, preceded, spaces, tag, Parser},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day4 {
+    cards: Vec<Card>,
+}
+
+impl Solution for Day4 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            cards: parse_lines(input, card())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 4,
+    solution: Day4,
+    input: "data/day4.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day4_demo.txt",
+            expected: Answer::Integer(13),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day4_demo.txt",
+            expected: Answer::Integer(30),
+        },
+    ],
+}
+
+fn part1(day: &Day4) -> Result<i32> {
+    let mut sum = 0;
+
+    for card in &day.cards {
+        let total_wins = handle_game(card);
+
+        sum += total_wins_to_score(total_wins);
+    }
+
+    return Ok(sum);
+}
+
+fn total_wins_to_score(total_wins: i32) -> i32 {
+    if total_wins == 0 {
+        return 0;
+    }
+
+    let index = total_wins as u32 - 1_u32;
+    let score: i32 = 2_i32.pow(index);
+
+    return score;
+}
+
+fn handle_game(card: &Card) -> i32 {
+    let mut total_wins = 0;
+
+    for my_number in &card.my_numbers {
+        if !card.winning_numbers.contains(my_number) {
+            continue;
+        }
+
+        total_wins += 1;
+    }
+
+    return total_wins;
+}
+
+struct Card {
+    winning_numbers: Vec<i32>,
+    my_numbers: Vec<i32>,
+}
+
+/// `Card 1: 41 48 83 | 83 86  6`
+fn card<'a>() -> impl Parser<'a, Card> {
+    let number = delimited(pair(tag("Card"), spaces()), integer::<usize>(), tag(":"));
+
+    return map(
+        pair(number, pair(numbers(), preceded(tag(" |"), numbers()))),
+        |(_, (winning_numbers, my_numbers))| Card {
+            winning_numbers,
+            my_numbers,
+        },
+    );
+}
+
+fn part2(day: &Day4) -> Result<i32> {
+    let total_games = day.cards.len();
+
+    // Goes from card number to total number held
+    // let mut total_scratch_cards: HashMap<i32, i32> = HashMap::new();
+
+    let mut total_scratch_cards = Vec::with_capacity(total_games);
+
+    // Goes from card number to number of game of cards you win
+    // let mut scratch_card_winnings: HashMap<i32, i32> = HashMap::new();
+
+    let mut scratch_card_winnings = Vec::with_capacity(total_games);
+
+    for card in &day.cards {
+        total_scratch_cards.push(1);
+
+        let total_wins = handle_game(card);
+
+        scratch_card_winnings.push(total_wins);
+    }
+
+    for (game_number, cards_won) in scratch_card_winnings.iter().enumerate() {
+        if *cards_won == 0 {
+            continue;
+        }
+
+        let multiplier = total_scratch_cards.get(game_number).unwrap().clone();
+
+        for i in 1..=*cards_won {
+            let winning_card_number = game_number + i as usize;
+
+            let cur = match total_scratch_cards.get_mut(winning_card_number) {
+                Some(x) => x,
+                None => {
+                    return Err(Error::unsolvable(format!(
+                        "card {} wins cards past the end of the table",
+                        game_number + 1
+                    )))
+                }
+            };
+            *cur += multiplier;
+        }
+    }
+
+    let sum = total_scratch_cards.iter().sum();
+
+    return Ok(sum);
+}
+
+/// `size` cards, none of which win cards past the end of the table, or so many copies that the
+/// total no longer fits in an `i32`
+fn generate(generator: &mut Generator) -> String {
+    let cards = generator.size_or(200);
+    let most = i32::MAX / cards.max(1) as i32;
+    let mut copies = vec![1; cards];
+    let mut lines = vec![];
+
+    for i in 0..cards {
+        let mut numbers: Vec<i32> = (1..100).collect();
+        generator.shuffle(&mut numbers);
+        let winning = &numbers[..10];
+
+        // Each copy of this card wins a copy of each of the next `matches` cards
+        let mut matches = generator.range(0..=10).min(cards - i - 1);
+        while copies[i + 1..=i + matches]
+            .iter()
+            .any(|&c| c > most - copies[i])
+        {
+            matches -= 1;
+        }
+        for j in i + 1..=i + matches {
+            copies[j] += copies[i];
+        }
+
+        let mut mine: Vec<i32> = winning[..matches].to_vec();
+        mine.extend(&numbers[10..35 - matches]);
+        generator.shuffle(&mut mine);
+
+        let show = |numbers: &[i32]| -> String {
+            let numbers: Vec<String> = numbers.iter().map(|n| format!("{:>2}", n)).collect();
+            return numbers.join(" ");
+        };
+
+        lines.push(format!(
+            "Card {:>3}: {} | {}",
+            i + 1,
+            show(winning),
+            show(&mine)
+        ));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day4_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 13);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day4_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 30);
+}
+
+/// The scratchcards held at the end, found by scratching every copy one at a time
+#[cfg(test)]
+fn brute_force_cards(cards: &[Card]) -> i32 {
+    let mut pile: Vec<usize> = (0..cards.len()).collect();
+    let mut held = 0;
+
+    while let Some(card) = pile.pop() {
+        held += 1;
+
+        let wins = handle_game(&cards[card]) as usize;
+        pile.extend(card + 1..=card + wins);
+    }
+
+    return held;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, cards in 1..=10usize) {
+        let mut generator = Generator::new(seed, Some(cards));
+        let day = Day4::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_cards(&day.cards));
+    }
+}
@@ -0,0 +1,370 @@
+use crate::{
+    generate::Generator,
+    input::blocks,
+    parse::{integer, map, numbers, pair, parse_line, preceded, spaces, tag, Parser},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+pub struct Day5 {
+    seeds: Vec<i64>,
+    maps: HashMap<String, Vec<AlmanacRow>>,
+}
+
+impl Solution for Day5 {
+    fn parse(input: &str) -> Result<Self> {
+        let mut blocks = blocks(input);
+
+        let seeds = match blocks.next() {
+            Some(block) => parse_line(
+                block.first_line,
+                block.lines[0],
+                preceded(tag("seeds:"), numbers()),
+            )?,
+            None => return Err(Error::parse(0, 0, "expected `seeds:`")),
+        };
+
+        let mut maps = prepare_hashmap();
+
+        for block in blocks {
+            let name = block.lines[0];
+            let rows = match maps.get_mut(name) {
+                Some(x) => x,
+                None => return Err(unknown_map(block.first_line, name)),
+            };
+
+            for (i, line) in block.numbered().skip(1) {
+                rows.push(parse_line(i, line, almanac_row())?);
+            }
+        }
+
+        return Ok(Self { seeds, maps });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 5,
+    solution: Day5,
+    input: "data/day5.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day5_demo.txt",
+            expected: Answer::Integer(35),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day5_demo.txt",
+            expected: Answer::Integer(46),
+        },
+    ],
+}
+
+fn part1(day: &Day5) -> Result<i64> {
+    let locations = evaluate_locations(&day.maps, &day.seeds);
+
+    return lowest(&locations);
+}
+
+fn part2(day: &Day5) -> Result<i64> {
+    let seeds = seed_ranges(&day.seeds)?;
+
+    let locations = evaluate_locations(&day.maps, &seeds);
+
+    return lowest(&locations);
+}
+
+fn lowest(locations: &[i64]) -> Result<i64> {
+    return locations
+        .iter()
+        .min()
+        .copied()
+        .ok_or_else(|| Error::unsolvable("the almanac has no seeds"));
+}
+
+fn unknown_map(i: usize, name: &str) -> Error {
+    return Error::parse(i, 0, format!("unknown map `{}`", name));
+}
+
+fn evaluate_locations(map: &HashMap<String, Vec<AlmanacRow>>, seeds: &Vec<i64>) -> Vec<i64> {
+    fn evaluate(map: &HashMap<String, Vec<AlmanacRow>>, seed: i64) -> i64 {
+        let soil_almanacs = map.get("seed-to-soil map:").unwrap();
+        let soil = evaluate_almanac(seed, soil_almanacs);
+
+        let fertaliser_almanacs = map.get("soil-to-fertilizer map:").unwrap();
+        let fertaliser = evaluate_almanac(soil, fertaliser_almanacs);
+
+        let water_almanacs = map.get("fertilizer-to-water map:").unwrap();
+        let water = evaluate_almanac(fertaliser, water_almanacs);
+
+        let light_almanacs = map.get("water-to-light map:").unwrap();
+        let light = evaluate_almanac(water, light_almanacs);
+
+        let temp_almanacs = map.get("light-to-temperature map:").unwrap();
+        let temp = evaluate_almanac(light, temp_almanacs);
+
+        let humidity_almanacs = map.get("temperature-to-humidity map:").unwrap();
+        let humidity = evaluate_almanac(temp, humidity_almanacs);
+
+        let location_almanacs = map.get("humidity-to-location map:").unwrap();
+        let location = evaluate_almanac(humidity, location_almanacs);
+
+        return location;
+    }
+
+    let locations: Vec<i64> = seeds.par_iter().map(|&x| evaluate(map, x)).collect();
+
+    return locations;
+}
+
+fn evaluate_locations2(map: &HashMap<String, Vec<AlmanacRow>>, seeds: &Vec<SeedRange>) -> Vec<i64> {
+    fn evaluate_seed(map: &HashMap<String, Vec<AlmanacRow>>, seed: i64) -> i64 {
+        let soil_almanacs = map.get("seed-to-soil map:").unwrap();
+        let soil = evaluate_almanac(seed, soil_almanacs);
+
+        let fertaliser_almanacs = map.get("soil-to-fertilizer map:").unwrap();
+        let fertaliser = evaluate_almanac(soil, fertaliser_almanacs);
+
+        let water_almanacs = map.get("fertilizer-to-water map:").unwrap();
+        let water = evaluate_almanac(fertaliser, water_almanacs);
+
+        let light_almanacs = map.get("water-to-light map:").unwrap();
+        let light = evaluate_almanac(water, light_almanacs);
+
+        let temp_almanacs = map.get("light-to-temperature map:").unwrap();
+        let temp = evaluate_almanac(light, temp_almanacs);
+
+        let humidity_almanacs = map.get("temperature-to-humidity map:").unwrap();
+        let humidity = evaluate_almanac(temp, humidity_almanacs);
+
+        let location_almanacs = map.get("humidity-to-location map:").unwrap();
+        let location = evaluate_almanac(humidity, location_almanacs);
+
+        return location;
+    }
+
+    let mut locations = vec![];
+
+    for seed_range in seeds {
+        let low = evaluate_seed(map, seed_range.low);
+        let high = evaluate_seed(map, seed_range.high);
+
+        let tmp = vec![low, high];
+
+        let min = tmp.iter().min().unwrap();
+        locations.push(*min);
+    }
+
+    return locations;
+}
+
+fn evaluate_almanac(key: i64, almanacs: &Vec<AlmanacRow>) -> i64 {
+    for almanac in almanacs {
+        if key >= almanac.source && key < almanac.source + almanac.range {
+            let diff = key - almanac.source;
+            return almanac.destination + diff;
+        }
+    }
+
+    return key;
+}
+
+fn prepare_hashmap() -> HashMap<String, Vec<AlmanacRow>> {
+    let mut map = HashMap::new();
+
+    map.insert("seed-to-soil map:".to_string(), Vec::new());
+    map.insert("soil-to-fertilizer map:".to_string(), Vec::new());
+    map.insert("fertilizer-to-water map:".to_string(), Vec::new());
+    map.insert("water-to-light map:".to_string(), Vec::new());
+    map.insert("light-to-temperature map:".to_string(), Vec::new());
+    map.insert("temperature-to-humidity map:".to_string(), Vec::new());
+    map.insert("humidity-to-location map:".to_string(), Vec::new());
+
+    return map;
+}
+
+fn insert_ranges(map: &mut HashMap<i64, i64>, almanac_row: &AlmanacRow) {
+    for i in 0..almanac_row.range {
+        map.insert(almanac_row.source + i, almanac_row.destination + i);
+    }
+}
+
+struct AlmanacRow {
+    pub source: i64,
+    pub destination: i64,
+    pub range: i64,
+}
+
+/// `<destination> <source> <range>`
+fn almanac_row<'a>() -> impl Parser<'a, AlmanacRow> {
+    return map(
+        pair(
+            integer(),
+            pair(preceded(spaces(), integer()), preceded(spaces(), integer())),
+        ),
+        |(destination, (source, range))| AlmanacRow {
+            source,
+            destination,
+            range,
+        },
+    );
+}
+
+struct SeedRange {
+    pub low: i64,
+    pub high: i64,
+}
+
+/// Expands the seeds, read as pairs of a start and a length, into every seed they cover
+fn seed_ranges(seeds: &[i64]) -> Result<Vec<i64>> {
+    let pairs = seeds.chunks_exact(2);
+
+    if !pairs.remainder().is_empty() {
+        return Err(Error::unsolvable("the seeds do not pair up into ranges"));
+    }
+
+    let mut output = vec![];
+
+    for pair in pairs {
+        for i in 0..pair[1] {
+            output.push(pair[0] + i);
+        }
+    }
+
+    return Ok(output);
+}
+
+/// `size` pairs of seeds, and seven maps of up to 40 rows each
+fn generate(generator: &mut Generator) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil map:",
+        "soil-to-fertilizer map:",
+        "fertilizer-to-water map:",
+        "water-to-light map:",
+        "light-to-temperature map:",
+        "temperature-to-humidity map:",
+        "humidity-to-location map:",
+    ];
+    const LIMIT: i64 = 1 << 32;
+
+    let mut seeds = vec![];
+    for _ in 0..generator.size_or(10).max(1) {
+        seeds.push(generator.range(0..LIMIT).to_string());
+        seeds.push(generator.range(1..=10_000_i64).to_string());
+    }
+
+    let mut blocks = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for name in MAPS {
+        let mut block = vec![name.to_string()];
+
+        for _ in 0..generator.range(5..=40) {
+            let range = generator.range(1..LIMIT / 16);
+            block.push(format!(
+                "{} {} {}",
+                generator.range(0..LIMIT - range),
+                generator.range(0..LIMIT - range),
+                range
+            ));
+        }
+
+        blocks.push(block.join("\n"));
+    }
+
+    return blocks.join("\n\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day5_demo.txt";
+    let lowest = part1(&crate::load(path)).unwrap();
+    assert_eq!(lowest, 35);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day5_demo.txt";
+    let lowest = part2(&crate::load(path)).unwrap();
+    assert_eq!(lowest, 46);
+}
+
+/// The lowest location of any seed in the ranges, found by following every seed through the
+/// rows of each map, given as `(destination, source, range)`
+#[cfg(test)]
+fn brute_force_lowest(seeds: &[i64], maps: &[Vec<(i64, i64, i64)>]) -> i64 {
+    let mut lowest = i64::MAX;
+
+    for pair in seeds.chunks(2) {
+        for seed in pair[0]..pair[0] + pair[1] {
+            let mut number = seed;
+
+            for rows in maps {
+                let row = rows
+                    .iter()
+                    .find(|(_, source, range)| (*source..source + range).contains(&number));
+                if let Some((destination, source, _)) = row {
+                    number = destination + number - source;
+                }
+            }
+
+            lowest = lowest.min(number);
+        }
+    }
+
+    return lowest;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_brute_force(
+        seeds in proptest::collection::vec((0..60i64, 1..10i64), 1..=3),
+        maps in proptest::collection::vec(
+            proptest::collection::vec((0..60i64, 0..60i64, 1..20i64), 0..=4),
+            7,
+        ),
+    ) {
+        const NAMES: [&str; 7] = [
+            "seed-to-soil map:",
+            "soil-to-fertilizer map:",
+            "fertilizer-to-water map:",
+            "water-to-light map:",
+            "light-to-temperature map:",
+            "temperature-to-humidity map:",
+            "humidity-to-location map:",
+        ];
+
+        let seeds: Vec<i64> = seeds.into_iter().flat_map(|(start, length)| [start, length]).collect();
+        let seed_line = seeds.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" ");
+
+        let mut blocks = vec![format!("seeds: {}", seed_line)];
+        for (name, rows) in NAMES.iter().zip(&maps) {
+            let mut block = vec![name.to_string()];
+            for (destination, source, range) in rows {
+                block.push(format!("{} {} {}", destination, source, range));
+            }
+            blocks.push(block.join("\n"));
+        }
+
+        let day = Day5::parse(&blocks.join("\n\n")).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_lowest(&seeds, &maps));
+    }
+}
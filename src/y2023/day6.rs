@@ -0,0 +1,170 @@
+use crate::{
+    generate::Generator,
+    lines,
+    parse::{numbers, parse_line, preceded, tag},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day6 {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+}
+
+impl Solution for Day6 {
+    fn parse(input: &str) -> Result<Self> {
+        let lines = lines(input);
+
+        if lines.len() != 2 {
+            return Err(Error::parse(
+                lines.len().min(2),
+                0,
+                format!("expected 2 lines, found {}", lines.len()),
+            ));
+        }
+
+        let times = parse_line(0, &lines[0], preceded(tag("Time:"), numbers()))?;
+        let distances = parse_line(1, &lines[1], preceded(tag("Distance:"), numbers()))?;
+
+        if times.len() != distances.len() {
+            return Err(Error::parse(
+                1,
+                0,
+                format!(
+                    "found {} times but {} distances",
+                    times.len(),
+                    distances.len()
+                ),
+            ));
+        }
+
+        return Ok(Self { times, distances });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 6,
+    solution: Day6,
+    input: "data/day6.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day6_demo.txt",
+            expected: Answer::Integer(288),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day6_demo.txt",
+            expected: Answer::Integer(71503),
+        },
+    ],
+}
+
+fn part2(day: &Day6) -> Result<i64> {
+    let big_time = convert_nums_to_big_num(0, &day.times)?;
+    let big_distance = convert_nums_to_big_num(1, &day.distances)?;
+
+    let mut total_victories = 0;
+
+    for time_held in 0..=big_time {
+        let time_remaining = big_time - time_held;
+        let speed = time_held;
+
+        let my_distance = calculate_distance(speed, time_remaining);
+
+        if my_distance > big_distance {
+            total_victories += 1;
+        }
+    }
+
+    return Ok(total_victories);
+}
+
+fn convert_nums_to_big_num(i: usize, nums: &[i64]) -> Result<i64> {
+    let mut string = String::new();
+
+    for num in nums {
+        string.push_str(&num.to_string());
+    }
+
+    return string
+        .parse()
+        .map_err(|e| Error::parse(i, 0, format!("could not join `{}`: {}", string, e)));
+}
+
+fn part1(day: &Day6) -> Result<i64> {
+    let mut victories = vec![];
+
+    for (time, distance) in day.times.iter().zip(day.distances.iter()) {
+        let mut total_victories = 0;
+
+        for time_held in 0..=*time {
+            let time_remaining = *time - time_held;
+            let speed = time_held;
+
+            let my_distance = calculate_distance(speed, time_remaining);
+
+            if my_distance > *distance {
+                total_victories += 1;
+            }
+        }
+
+        victories.push(total_victories);
+    }
+    return Ok(victories.iter().product());
+}
+
+fn calculate_distance(speed: i64, time: i64) -> i64 {
+    // Speed in milimetres per milisecond
+    // Time in miliseconds remaining
+
+    return speed * time;
+}
+
+/// `size` races, each of which can be won
+fn generate(generator: &mut Generator) -> String {
+    let mut times = vec![];
+    let mut distances = vec![];
+
+    for _ in 0..generator.size_or(4).max(1) {
+        let time: i64 = generator.range(7..100);
+        // The best run holds the button for half the race
+        let best = (time / 2) * (time - time / 2);
+
+        times.push(format!("{:>4}", time));
+        distances.push(format!("{:>4}", generator.range(1..best)));
+    }
+
+    return format!(
+        "Time:     {}\nDistance: {}",
+        times.join(" "),
+        distances.join(" ")
+    );
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day6_demo.txt";
+    let product = part1(&crate::load(path)).unwrap();
+    assert_eq!(product, 288);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day6_demo.txt";
+    let total = part2(&crate::load(path)).unwrap();
+    assert_eq!(total, 71503);
+}
@@ -1,31 +1,71 @@
-use std::{cmp::Ordering, collections::HashMap, fmt::Display};
-
-use crate::read_lines;
-
-pub fn day7() {
-    let data = "data/day7.txt";
-    let sum = part1(data);
-    println!("Day 7 Part 1: {}", sum);
-    let sum = part2(data);
-    println!("Day 7 Part 2: {}", sum);
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use crate::{
+    generate::Generator,
+    parse::{integer, map, pair, parse_lines, spaces, take_while, terminated, Cursor, Parser},
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day7 {
+    /// Each hand as written, its cards' values and its bid
+    hands: Vec<(String, Vec<i32>, i32)>,
 }
 
-fn part1(path: &str) -> i32 {
-    let lines = read_lines(path);
+impl Solution for Day7 {
+    fn parse(input: &str) -> Result<Self> {
+        let hand = map(
+            pair(terminated(cards(), spaces()), integer()),
+            |((hand, cards), bid)| (hand.to_string(), cards, bid),
+        );
 
-    let mut hands = vec![];
+        return Ok(Self {
+            hands: parse_lines(input, hand)?,
+        });
+    }
 
-    for line in lines {
-        let split: Vec<&str> = line.split(" ").collect();
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
 
-        assert_eq!(split.len(), 2);
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
 
-        let bid = split[1].parse::<i32>().unwrap();
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
 
-        let hand = split[0].to_string();
+register! {
+    year: 2023,
+    day: 7,
+    solution: Day7,
+    input: "data/day7.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day7_demo.txt",
+            expected: Answer::Integer(6440),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day7_demo.txt",
+            expected: Answer::Integer(5905),
+        },
+    ],
+}
 
-        hands.push(Hand::new(hand, bid, false));
-    }
+fn part1(day: &Day7) -> Result<i32> {
+    let mut hands: Vec<Hand> = day
+        .hands
+        .iter()
+        .map(|(hand, cards, bid)| Hand::new(hand, cards.clone(), *bid, false))
+        .collect();
 
     hands.sort_by(|a, b| a.cmp(b));
 
@@ -34,25 +74,15 @@ fn part1(path: &str) -> i32 {
         sum += (i + 1) as i32 * hand.bid;
     }
 
-    return sum;
+    return Ok(sum);
 }
 
-fn part2(path: &str) -> i32 {
-    let lines = read_lines(path);
-
-    let mut hands = vec![];
-
-    for line in lines {
-        let split: Vec<&str> = line.split(" ").collect();
-
-        assert_eq!(split.len(), 2);
-
-        let bid = split[1].parse::<i32>().unwrap();
-
-        let hand = split[0].to_string();
-
-        hands.push(Hand::new(hand, bid, true));
-    }
+fn part2(day: &Day7) -> Result<i32> {
+    let mut hands: Vec<Hand> = day
+        .hands
+        .iter()
+        .map(|(hand, cards, bid)| Hand::new(hand, cards.clone(), *bid, true))
+        .collect();
 
     hands.sort_by(|a, b| a.alt_cmp(b));
 
@@ -61,22 +91,18 @@ fn part2(path: &str) -> i32 {
         sum += (i + 1) as i32 * hand.bid;
     }
 
-    return sum;
+    return Ok(sum);
 }
 
-#[derive(PartialEq, Eq, PartialOrd)]
-struct Hand {
-    pub hand: String,
-    pub cards: Vec<i32>,
-    pub hand_type: HandType,
-    pub bid: i32,
-}
+/// A hand of five cards like `32T3K`, with the values of its cards
+fn cards<'a>() -> impl Parser<'a, (&'a str, Vec<i32>)> {
+    return |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        let hand = take_while("a hand of cards", |c| c != ' ')(cursor)?;
 
-impl Hand {
-    pub fn new(hand: String, bid: i32, alt: bool) -> Self {
         let mut cards: Vec<i32> = vec![];
 
-        for c in hand.chars() {
+        for (j, c) in hand.char_indices() {
             if c.is_digit(10) {
                 cards.push(c.to_digit(10).unwrap() as i32);
             } else if c == 'T' {
@@ -90,12 +116,35 @@ impl Hand {
             } else if c == 'A' {
                 cards.push(14);
             } else {
-                panic!("Could not parse hand {}", hand);
+                return Err(start.error_at(
+                    &hand[j..],
+                    format!("could not parse card `{}` in hand {}", c, hand),
+                ));
             }
         }
 
-        assert_eq!(cards.len(), 5);
+        if cards.len() != 5 {
+            return Err(start.error(format!(
+                "expected 5 cards in hand {}, found {}",
+                hand,
+                cards.len()
+            )));
+        }
+
+        return Ok((hand, cards));
+    };
+}
+
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Hand {
+    pub hand: String,
+    pub cards: Vec<i32>,
+    pub hand_type: HandType,
+    pub bid: i32,
+}
 
+impl Hand {
+    pub fn new(hand: &str, cards: Vec<i32>, bid: i32, alt: bool) -> Self {
         let hand_type = if alt {
             HandType::new_alt(&cards)
         } else {
@@ -103,7 +152,7 @@ impl Hand {
         };
 
         return Self {
-            hand: hand,
+            hand: hand.to_string(),
             cards: cards,
             hand_type: hand_type,
             bid: bid,
@@ -267,16 +316,35 @@ impl HandType {
     }
 }
 
+/// `size` different hands with their bids
+fn generate(generator: &mut Generator) -> String {
+    let hands = generator.size_or(1000).min(13_usize.pow(5));
+    let mut seen = HashSet::new();
+    let mut lines = vec![];
+
+    while lines.len() < hands {
+        // Drawing from a few of the labels makes pairs and better hands more likely
+        let labels = generator.range(2..=13);
+        let hand = generator.word(&"23456789TJQKA"[13 - labels..], 5);
+
+        if seen.insert(hand.clone()) {
+            lines.push(format!("{} {}", hand, generator.range(1..=1000)));
+        }
+    }
+
+    return lines.join("\n");
+}
+
 #[test]
 fn test_part1() {
     let data = "data_demo/day7_demo.txt";
-    let hand = part1(data);
+    let hand = part1(&crate::load(data)).unwrap();
     assert_eq!(hand, 6440);
 }
 
 #[test]
 fn test_part2() {
     let data = "data_demo/day7_demo.txt";
-    let hand = part2(data);
+    let hand = part2(&crate::load(data)).unwrap();
     assert_eq!(hand, 5905);
 }
@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+
+use log::debug;
+
+use crate::{
+    generate::Generator,
+    lines,
+    parse::{cells, delimited, identifier, key_value, parse_line, tag},
+    solution::register,
+    Answer, Demo, Error, Result, Solution,
+};
+
+pub struct Day8 {
+    turns: Vec<LR>,
+    directions: HashMap<String, (String, String)>,
+}
+
+impl Solution for Day8 {
+    fn parse(input: &str) -> Result<Self> {
+        let lines = lines(input);
+
+        let lr = lines.first().map_or("", |line| line.as_str());
+
+        let turn = |c| match c {
+            'L' => Some(LR::Left),
+            'R' => Some(LR::Right),
+            _ => None,
+        };
+        let turns = parse_line(0, lr, cells(turn))?;
+
+        // `AAA = (BBB, CCC)`
+        let node = key_value(
+            identifier(),
+            tag(" = "),
+            delimited(
+                tag("("),
+                key_value(identifier(), tag(", "), identifier()),
+                tag(")"),
+            ),
+        );
+
+        let mut directions = HashMap::new();
+
+        for (i, line) in lines.iter().enumerate().skip(2) {
+            let (source, (left, right)) = parse_line(i, line, &node)?;
+
+            directions.insert(source.to_string(), (left.to_string(), right.to_string()));
+        }
+
+        return Ok(Self { turns, directions });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 8,
+    solution: Day8,
+    input: "data/day8.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day8_demo1.txt",
+            expected: Answer::Integer(2),
+        },
+        Demo {
+            part: 1,
+            input: "data_demo/day8_demo2.txt",
+            expected: Answer::Integer(6),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day8_demo3.txt",
+            expected: Answer::Integer(6),
+        },
+    ],
+}
+
+fn part1(day: &Day8) -> Result<i64> {
+    let turns = &day.turns;
+    let directions = &day.directions;
+
+    let mut count = 0;
+    let mut current = "AAA";
+    let mut met_goal = false;
+    let goal = "ZZZ";
+
+    while !met_goal {
+        for turn in turns.iter() {
+            let direction = next_node(directions, current)?;
+
+            match turn {
+                LR::Left => current = &direction.0,
+                LR::Right => current = &direction.1,
+            }
+
+            count += 1;
+
+            if current == goal {
+                met_goal = true;
+                break;
+            }
+        }
+    }
+
+    return Ok(count);
+}
+
+fn part2(day: &Day8) -> Result<i64> {
+    let turns = &day.turns;
+    let directions = &day.directions;
+
+    let current: Vec<&str> = directions
+        .keys()
+        .filter(|source| source.ends_with("A"))
+        .map(|source| source.as_str())
+        .collect();
+
+    let mut times = vec![];
+
+    for source in current {
+        let mut count = 0;
+        let mut met_goal = false;
+        let mut current = source;
+
+        while !met_goal {
+            for turn in turns.iter() {
+                let direction = next_node(directions, current)?;
+
+                match turn {
+                    LR::Left => current = &direction.0,
+                    LR::Right => current = &direction.1,
+                }
+
+                count += 1;
+
+                if current.ends_with("Z") {
+                    met_goal = true;
+                    break;
+                }
+            }
+        }
+
+        times.push(count);
+    }
+
+    debug!("{:?}", times);
+
+    if times.is_empty() {
+        return Err(Error::unsolvable("no node ends with `A`"));
+    }
+
+    return rec_lmc(&times);
+}
+
+fn next_node<'a>(
+    directions: &'a HashMap<String, (String, String)>,
+    current: &str,
+) -> Result<&'a (String, String)> {
+    return directions
+        .get(current)
+        .ok_or_else(|| Error::unsolvable(format!("there is no node `{}`", current)));
+}
+
+fn rec_lmc(nums: &[i64]) -> Result<i64> {
+    let n = nums.len();
+    if n == 1 {
+        return Ok(nums[0]);
+    }
+
+    if n == 2 {
+        return lcm(nums[0], nums[1]);
+    }
+
+    return lcm(nums[0], rec_lmc(&nums[1..n])?);
+}
+
+fn lcm(first: i64, second: i64) -> Result<i64> {
+    return (first / gcd(first, second))
+        .checked_mul(second)
+        .ok_or_else(|| Error::unsolvable("the ghosts take too many steps to count"));
+}
+
+fn gcd(first: i64, second: i64) -> i64 {
+    let mut max = first;
+    let mut min = second;
+    if min > max {
+        let val = max;
+        max = min;
+        min = val;
+    }
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return min;
+        }
+
+        max = min;
+        min = res;
+    }
+}
+
+fn has_met_goal(current: &Vec<&str>) -> bool {
+    for cur in current {
+        if !cur.ends_with("Z") {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+enum LR {
+    Left,
+    Right,
+}
+
+/// A map for `size` ghosts, at most 13. Each ghost's path to a `Z` node is the length of the instructions
+/// times a different prime, as in the puzzle input
+fn generate(generator: &mut Generator) -> String {
+    const PRIMES: [usize; 14] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+    // The answer is the instructions' length times the primes, so one prime is left out to keep
+    // it within an i64 however many instructions there are
+    let ghosts = generator.size_or(6).clamp(1, PRIMES.len() - 1);
+    let len = generator.range(5..=20);
+    let turns: Vec<char> = generator.word("LR", len).chars().collect();
+
+    let mut primes = PRIMES.to_vec();
+    generator.shuffle(&mut primes);
+
+    // Every node name ends in a letter other than `A` or `Z`, apart from each ghost's start and
+    // goal
+    let mut chains: Vec<Vec<String>> = vec![];
+    let mut seen = HashSet::new();
+
+    for (ghost, prime) in primes.iter().enumerate().take(ghosts) {
+        let steps = turns.len() * prime;
+        let (start, goal) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => loop {
+                let prefix = generator.word("BCDEFGHIJKLMNOPQRSTUVWXY", 2);
+                let start = format!("{}A", prefix);
+                if seen.insert(start.clone()) {
+                    break (start, format!("{}Z", prefix));
+                }
+            },
+        };
+
+        let mut chain = vec![start];
+        while chain.len() < steps {
+            let name = generator.word("BCDEFGHIJKLMNOPQRSTUVWXY", 3);
+            if seen.insert(name.clone()) {
+                chain.push(name);
+            }
+        }
+        chain.push(goal);
+
+        chains.push(chain);
+    }
+
+    let all: Vec<&String> = chains.iter().flatten().collect();
+    let mut nodes = vec![];
+
+    for chain in &chains {
+        let mut directions = vec![];
+
+        for (step, name) in chain[..chain.len() - 1].iter().enumerate() {
+            // The instruction leads on along the chain, and the other way somewhere else
+            let onward = chain[step + 1].clone();
+            let elsewhere = generator.choose(&all).to_string();
+
+            let direction = match turns[step % turns.len()] {
+                'L' => (onward, elsewhere),
+                _ => (elsewhere, onward),
+            };
+            directions.push((name.clone(), direction));
+        }
+
+        // The goal leads where the start does, so ghosts go round in a loop
+        let goal = chain.last().unwrap().clone();
+        let start = directions[0].1.clone();
+        directions.push((goal, start));
+
+        nodes.extend(directions);
+    }
+
+    generator.shuffle(&mut nodes);
+
+    let lines: Vec<String> = nodes
+        .iter()
+        .map(|(name, (left, right))| format!("{} = ({}, {})", name, left, right))
+        .collect();
+
+    return format!(
+        "{}\n\n{}",
+        turns.iter().collect::<String>(),
+        lines.join("\n")
+    );
+}
+
+#[test]
+fn test_part1() {
+    let data = "data_demo/day8_demo1.txt";
+    let count = part1(&crate::load(data)).unwrap();
+    assert_eq!(count, 2);
+    let data = "data_demo/day8_demo2.txt";
+    let count = part1(&crate::load(data)).unwrap();
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day8_demo3.txt";
+    let count = part2(&crate::load(path)).unwrap();
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn test_lcm() {
+    let nums = vec![4, 6, 8];
+    let lcm = rec_lmc(&nums).unwrap();
+    assert_eq!(lcm, 24);
+
+    assert!(matches!(
+        rec_lmc(&[i64::MAX - 1, i64::MAX - 2]),
+        Err(Error::Unsolvable(_))
+    ));
+}
+
+/// The steps until every ghost is on a `Z` node at once, found by walking them all together
+#[cfg(test)]
+fn brute_force_ghosts(day: &Day8) -> i64 {
+    let mut current: Vec<&str> = day
+        .directions
+        .keys()
+        .filter(|source| source.ends_with("A"))
+        .map(|source| source.as_str())
+        .collect();
+
+    let mut count = 0;
+    for turn in day.turns.iter().cycle() {
+        if has_met_goal(&current) {
+            break;
+        }
+
+        for ghost in current.iter_mut() {
+            let direction = &day.directions[*ghost];
+            *ghost = match turn {
+                LR::Left => &direction.0,
+                LR::Right => &direction.1,
+            };
+        }
+        count += 1;
+    }
+
+    return count;
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(32))]
+
+    #[test]
+    fn test_part2_matches_brute_force(seed in proptest::num::u64::ANY, ghosts in 1..=2usize) {
+        let mut generator = Generator::new(seed, Some(ghosts));
+        let day = Day8::parse(&generate(&mut generator)).unwrap();
+
+        proptest::prop_assert_eq!(part2(&day).unwrap(), brute_force_ghosts(&day));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    generate::Generator,
+    parse::{numbers, parse_lines},
+    solution::register,
+    Answer, Demo, Result, Solution,
+};
+
+pub struct Day9 {
+    sequences: Vec<Vec<i64>>,
+}
+
+impl Solution for Day9 {
+    fn parse(input: &str) -> Result<Self> {
+        return Ok(Self {
+            sequences: parse_lines(input, numbers())?,
+        });
+    }
+
+    fn generate(generator: &mut Generator) -> String {
+        return generate(generator);
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        return Ok(part1(self)?.into());
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        return Ok(part2(self)?.into());
+    }
+}
+
+register! {
+    year: 2023,
+    day: 9,
+    solution: Day9,
+    input: "data/day9.txt",
+    demos: &[
+        Demo {
+            part: 1,
+            input: "data_demo/day9_demo.txt",
+            expected: Answer::Integer(114),
+        },
+        Demo {
+            part: 2,
+            input: "data_demo/day9_demo.txt",
+            expected: Answer::Integer(2),
+        },
+    ],
+}
+
+fn part1(day: &Day9) -> Result<i64> {
+    let mut sums = 0;
+    for sequence in &day.sequences {
+        let mut going_down = true;
+
+        let mut diffs = vec![sequence.clone()];
+
+        while going_down {
+            let diff = find_differences(diffs.last().unwrap());
+
+            if is_all_zero(&diff) {
+                going_down = false;
+            }
+
+            diffs.push(diff);
+        }
+
+        let mut increase = 0;
+
+        for diff in diffs.iter().rev() {
+            // A sequence of one number has no differences at all
+            increase += diff.last().unwrap_or(&0);
+        }
+
+        sums += increase;
+    }
+
+    return Ok(sums);
+}
+
+fn part2(day: &Day9) -> Result<i64> {
+    let number_lines = reverse_all_num_lines(&day.sequences);
+
+    let mut sums = 0;
+    for sequence in number_lines {
+        let mut going_down = true;
+
+        let mut diffs = vec![sequence.clone()];
+
+        while going_down {
+            let diff = find_differences(diffs.last().unwrap());
+
+            if is_all_zero(&diff) {
+                going_down = false;
+            }
+
+            diffs.push(diff);
+        }
+
+        let mut increase = 0;
+
+        for diff in diffs.iter().rev() {
+            // A sequence of one number has no differences at all
+            increase += diff.last().unwrap_or(&0);
+        }
+
+        sums += increase;
+    }
+
+    return Ok(sums);
+}
+
+fn reverse_all_num_lines(nums: &Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+    let mut out = vec![];
+
+    for line in nums {
+        let tmp: Vec<i64> = line.iter().rev().cloned().collect();
+
+        out.push(tmp);
+    }
+
+    return out;
+}
+
+fn find_differences(nums: &Vec<i64>) -> Vec<i64> {
+    let mut out = vec![];
+
+    for i in 0..nums.len() - 1 {
+        let diff = nums[i + 1] - nums[i];
+        out.push(diff);
+    }
+
+    return out;
+}
+
+fn is_all_zero(nums: &Vec<i64>) -> bool {
+    for i in nums {
+        if *i != 0 {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+/// `size` sequences of 21 values, each a polynomial of degree up to 6
+fn generate(generator: &mut Generator) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..generator.size_or(200) {
+        // Start from a constant row of differences and sum it up, once per degree
+        let mut values = [generator.range(-5..=5_i64); 21];
+
+        for _ in 0..generator.range(0..=6) {
+            let mut total = generator.range(-20..=20);
+            for value in values.iter_mut() {
+                let difference = *value;
+                *value = total;
+                total += difference;
+            }
+        }
+
+        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+        lines.push(values.join(" "));
+    }
+
+    return lines.join("\n");
+}
+
+#[test]
+fn test_part1() {
+    let path = "data_demo/day9_demo.txt";
+    let sum = part1(&crate::load(path)).unwrap();
+    assert_eq!(sum, 114)
+}
+
+#[test]
+fn test_diff() {
+    let seq: Vec<i64> = vec![1, 3, 6, 10, 15, 21, 28];
+    let diff = find_differences(&seq);
+
+    let correct: Vec<i64> = vec![2, 3, 4, 5, 6, 7];
+
+    for (i, j) in diff.iter().zip(correct.iter()) {
+        assert_eq!(i, j);
+    }
+}
+
+#[test]
+fn test_part2() {
+    let path = "data_demo/day9_demo.txt";
+    let sum = part2(&crate::load(path)).unwrap();
+    assert_eq!(sum, 2);
+}